@@ -0,0 +1,36 @@
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use is_default::IsDefault;
+
+/// A 4 MiB buffer of default values.
+const BYTES: usize = 4 << 20;
+
+fn bench<T>(c: &mut Criterion, name: &str)
+where
+    T: IsDefault + Default + Clone,
+{
+    let buf = vec![T::default(); BYTES / size_of::<T>()];
+    let mut group = c.benchmark_group(name);
+    group.throughput(Throughput::Bytes(BYTES as u64));
+
+    group.bench_function(BenchmarkId::new("loop", "4MiB"), |b| {
+        b.iter(|| black_box(buf.as_slice()).iter().all(IsDefault::is_default))
+    });
+    group.bench_function(BenchmarkId::new("is_default_slice", "4MiB"), |b| {
+        b.iter(|| T::is_default_slice(black_box(buf.as_slice())))
+    });
+
+    group.finish();
+}
+
+fn slices(c: &mut Criterion) {
+    bench::<u8>(c, "u8");
+    bench::<u16>(c, "u16");
+    bench::<u32>(c, "u32");
+    bench::<u64>(c, "u64");
+    bench::<u128>(c, "u128");
+    bench::<f32>(c, "f32");
+    bench::<f64>(c, "f64");
+}
+
+criterion_group!(benches, slices);
+criterion_main!(benches);
@@ -0,0 +1,203 @@
+use crate::utils::crate_path;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Fields, LitStr, Type, ext::IdentExt};
+
+/// The kind of entries to derive.
+#[derive(Clone, Copy)]
+pub enum Kind {
+    Args,
+    Env,
+}
+
+impl Kind {
+    fn attr(self) -> &'static str {
+        match self {
+            Kind::Args => "to_args",
+            Kind::Env => "to_env",
+        }
+    }
+
+    fn trait_name(self) -> &'static str {
+        match self {
+            Kind::Args => "ToArgs",
+            Kind::Env => "ToEnv",
+        }
+    }
+
+    fn default_name(self, field: &str) -> String {
+        match self {
+            Kind::Args => field.replace('_', "-"),
+            Kind::Env => field.to_uppercase(),
+        }
+    }
+
+    fn separator(self) -> &'static str {
+        match self {
+            Kind::Args => "-",
+            Kind::Env => "_",
+        }
+    }
+}
+
+#[derive(Default)]
+struct Options {
+    name: Option<String>,
+    prefix: Option<String>,
+    flatten: bool,
+    skip: bool,
+}
+
+fn parse_options(kind: Kind, attrs: &[Attribute], container: bool) -> syn::Result<Options> {
+    let mut opts = Options::default();
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident(kind.attr())) {
+        attr.parse_nested_meta(|meta| {
+            if container && meta.path.is_ident("prefix") {
+                opts.prefix = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if !container && meta.path.is_ident("name") {
+                opts.name = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if !container && meta.path.is_ident("flatten") {
+                opts.flatten = true;
+            } else if !container && meta.path.is_ident("skip") {
+                opts.skip = true;
+            } else if container {
+                return Err(meta.error(format!(
+                    "unsupported {} attribute, expected `prefix`",
+                    kind.attr()
+                )));
+            } else {
+                return Err(meta.error(format!(
+                    "unsupported {} attribute, expected `name`, `flatten` or `skip`",
+                    kind.attr()
+                )));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(opts)
+}
+
+fn is_bool(ty: &Type) -> bool {
+    matches!(ty, Type::Path(p) if p.qself.is_none() && p.path.is_ident("bool"))
+}
+
+pub fn derive(kind: Kind, input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let crate_path = crate_path(name.span());
+
+    let fields = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields_named) => &fields_named.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    format!(
+                        "{} can only be derived for structs with named fields",
+                        kind.trait_name()
+                    ),
+                ));
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                format!("{} can only be derived for structs", kind.trait_name()),
+            ));
+        }
+    };
+
+    let container = parse_options(kind, &input.attrs, true)?;
+    let mut writes = Vec::with_capacity(fields.len());
+
+    for field in fields {
+        let opts = parse_options(kind, &field.attrs, false)?;
+        if opts.skip {
+            continue;
+        }
+
+        let ident = field.ident.as_ref().unwrap();
+        let entry_name = opts
+            .name
+            .unwrap_or_else(|| kind.default_name(&ident.unraw().to_string()));
+
+        let write = match (kind, opts.flatten) {
+            (_, true) => {
+                let trait_ident = syn::Ident::new(kind.trait_name(), ident.span());
+                let method = match kind {
+                    Kind::Args => quote!(write_args),
+                    Kind::Env => quote!(write_env),
+                };
+                let nested = format!("{}{}", entry_name, kind.separator());
+                quote! {
+                    #crate_path::args::#trait_ident::#method(
+                        &self.#ident,
+                        &::std::format!("{}{}", prefix, #nested),
+                        out,
+                    );
+                }
+            }
+            (Kind::Args, false) if is_bool(&field.ty) => quote! {
+                #crate_path::args::push_arg(out, prefix, #entry_name, ::core::option::Option::None);
+            },
+            (Kind::Args, false) => quote! {
+                #crate_path::args::push_arg(
+                    out,
+                    prefix,
+                    #entry_name,
+                    ::core::option::Option::Some(#crate_path::args::ArgValue::to_arg_value(&self.#ident)),
+                );
+            },
+            (Kind::Env, false) => quote! {
+                #crate_path::args::push_env(
+                    out,
+                    prefix,
+                    #entry_name,
+                    #crate_path::args::ArgValue::to_arg_value(&self.#ident),
+                );
+            },
+        };
+
+        writes.push(quote! {
+            if !#crate_path::IsDefault::is_default(&self.#ident) {
+                #write
+            }
+        });
+    }
+
+    let (write_fn, to_fn, out_ty) = match kind {
+        Kind::Args => (
+            quote!(write_args),
+            quote!(to_args),
+            quote!(::std::vec::Vec<::std::ffi::OsString>),
+        ),
+        Kind::Env => (
+            quote!(write_env),
+            quote!(to_env),
+            quote!(::std::vec::Vec<(::std::ffi::OsString, ::std::ffi::OsString)>),
+        ),
+    };
+    let trait_ident = syn::Ident::new(kind.trait_name(), name.span());
+
+    let to_fn = container.prefix.map(|prefix| {
+        quote! {
+            fn #to_fn(&self) -> #out_ty {
+                let mut out = ::std::vec::Vec::new();
+                self.#write_fn(#prefix, &mut out);
+                out
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #crate_path::args::#trait_ident for #name #ty_generics #where_clause {
+            fn #write_fn(&self, prefix: &str, out: &mut #out_ty) {
+                #(#writes)*
+            }
+
+            #to_fn
+        }
+    })
+}
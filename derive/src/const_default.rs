@@ -0,0 +1,124 @@
+use crate::utils::{crate_path, default_variant};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Index, Member, Type};
+
+/// Scalar types supported by `is_default_const!`.
+const SCALARS: &[&str] = &[
+    "bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32",
+    "u64", "u128", "usize",
+];
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let crate_path = crate_path(name.span());
+
+    let (value, check) = match &input.data {
+        Data::Struct(data_struct) => {
+            let members: Vec<Member> = match &data_struct.fields {
+                Fields::Named(fields_named) => fields_named
+                    .named
+                    .iter()
+                    .filter_map(|f| f.ident.clone())
+                    .map(Member::Named)
+                    .collect(),
+                fields => (0..fields.len())
+                    .map(|i| Member::Unnamed(Index::from(i)))
+                    .collect(),
+            };
+            let values = data_struct.fields.iter().zip(&members).map(|(f, member)| {
+                let ty = &f.ty;
+                quote!(#member: <#ty as #crate_path::ConstDefault>::DEFAULT)
+            });
+            let value = quote!(Self { #(#values),* });
+
+            let check = data_struct
+                .fields
+                .iter()
+                .all(|f| is_primitive(&f.ty))
+                .then(|| {
+                    let checks = members
+                        .iter()
+                        .map(|member| quote!(#crate_path::Probe(&self.#member).is_default_const()));
+                    quote!(true #(&& #checks)*)
+                });
+
+            (value, check)
+        }
+
+        Data::Enum(enum_data) => match default_variant(enum_data)? {
+            Some(ident) => (
+                quote!(#name::#ident),
+                Some(quote!(matches!(self, #name::#ident))),
+            ),
+            None => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "ConstDefault for enums requires a #[default] or #[is_default] unit variant",
+                ));
+            }
+        },
+
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "ConstDefault cannot be derived for unions",
+            ));
+        }
+    };
+
+    let check = check.map(|check| {
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Returns `true` if `self` is default, in `const` contexts.
+                #[allow(dead_code)]
+                pub const fn is_default_const(&self) -> bool {
+                    #check
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #crate_path::ConstDefault for #name #ty_generics #where_clause {
+            const DEFAULT: Self = #value;
+        }
+
+        #check
+    })
+}
+
+fn is_scalar(ty: &Type) -> bool {
+    match ty {
+        Type::Path(p) if p.qself.is_none() => SCALARS.iter().any(|s| p.path.is_ident(s)),
+        Type::Paren(p) => is_scalar(&p.elem),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `is_default_const!` supports `ty`.
+fn is_primitive(ty: &Type) -> bool {
+    match ty {
+        Type::Array(a) => is_scalar(&a.elem),
+        Type::Reference(r) if r.mutability.is_none() => {
+            matches!(&*r.elem, Type::Path(p) if p.qself.is_none() && p.path.is_ident("str"))
+        }
+        Type::Tuple(t) => t.elems.is_empty(),
+        Type::Path(p) if p.qself.is_none() => {
+            let idents: Vec<String> = p
+                .path
+                .segments
+                .iter()
+                .map(|s| s.ident.to_string())
+                .collect();
+            is_scalar(ty)
+                || matches!(
+                    idents.iter().map(String::as_str).collect::<Vec<_>>()[..],
+                    ["Option"] | ["core" | "std", "option", "Option"]
+                )
+        }
+        Type::Paren(p) => is_primitive(&p.elem),
+        _ => false,
+    }
+}
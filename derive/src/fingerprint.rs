@@ -0,0 +1,127 @@
+use crate::utils::{bindings, crate_path, pattern};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Fields, Ident, LitStr, ext::IdentExt};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let crate_path = crate_path(name.span());
+
+    let body = match &input.data {
+        Data::Struct(data_struct) => {
+            let bindings = bindings(&data_struct.fields, "__f");
+            let hashes = hash_fields(&crate_path, &data_struct.fields, &bindings)?;
+            let pattern = pattern(&quote!(#name), &data_struct.fields, &bindings);
+            quote! {
+                let #pattern = self;
+                #hashes
+            }
+        }
+
+        Data::Enum(enum_data) => {
+            let arms = enum_data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let variant_name = ident.unraw().to_string();
+                    let bindings = bindings(&variant.fields, "__f");
+                    let hashes = hash_fields(&crate_path, &variant.fields, &bindings)?;
+                    let pattern = pattern(&quote!(#name::#ident), &variant.fields, &bindings);
+                    Ok(quote! {
+                        #pattern => {
+                            #crate_path::fingerprint::write_tag(state, #variant_name);
+                            #hashes
+                        }
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "DefaultInsensitiveHash cannot be derived for unions",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #crate_path::DefaultInsensitiveHash for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn hash_non_default<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+                #body
+            }
+        }
+    })
+}
+
+/// Returns the tag name of a field, or `None` if it is skipped.
+fn tag_name(attrs: &[Attribute], default: String) -> syn::Result<Option<String>> {
+    let mut name = Some(default);
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("fingerprint")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                if name.is_some() {
+                    name = Some(meta.value()?.parse::<LitStr>()?.value());
+                }
+            } else if meta.path.is_ident("skip") {
+                name = None;
+            } else {
+                return Err(
+                    meta.error("unsupported fingerprint attribute, expected `name` or `skip`")
+                );
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(name)
+}
+
+fn hash_fields(
+    crate_path: &TokenStream,
+    fields: &Fields,
+    bindings: &[Ident],
+) -> syn::Result<TokenStream> {
+    let mut tagged = Vec::with_capacity(bindings.len());
+
+    for (i, (field, binding)) in fields.iter().zip(bindings).enumerate() {
+        let default = match &field.ident {
+            Some(ident) => ident.unraw().to_string(),
+            None => i.to_string(),
+        };
+        if let Some(name) = tag_name(&field.attrs, default)? {
+            tagged.push((name, binding));
+        }
+    }
+
+    // Sorting by name makes the hash independent of declaration order.
+    tagged.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let hashes = tagged.iter().map(|(name, binding)| {
+        quote! {
+            if #crate_path::IsDefault::is_default(#binding) {
+                0
+            } else {
+                #crate_path::fingerprint::write_tag(state, #name);
+                #crate_path::DefaultInsensitiveHash::hash_non_default(#binding, state);
+                1
+            }
+        }
+    });
+
+    // The count ends the body, so that the fields of a nested value cannot
+    // run into the fields that follow it.
+    Ok(quote! {
+        let __count: usize = 0 #(+ #hashes)*;
+        #crate_path::fingerprint::write_len(state, __count);
+    })
+}
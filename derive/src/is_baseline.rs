@@ -0,0 +1,79 @@
+use crate::utils::{bindings, crate_path, pattern};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let crate_path = crate_path(name.span());
+
+    let body = match &input.data {
+        Data::Struct(data_struct) => {
+            let (lhs, rhs, checks) = compare(&crate_path, quote!(#name), &data_struct.fields);
+            quote! {
+                let #lhs = self;
+                let #rhs = baseline;
+                #checks
+            }
+        }
+
+        Data::Enum(enum_data) if enum_data.variants.is_empty() => quote!(match *self {}),
+
+        Data::Enum(enum_data) => {
+            let arms = enum_data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let (lhs, rhs, checks) =
+                    compare(&crate_path, quote!(#name::#ident), &variant.fields);
+                quote! {
+                    (#lhs, #rhs) => { #checks }
+                }
+            });
+            let fallback = (enum_data.variants.len() > 1).then(|| quote!(_ => false,));
+            quote! {
+                match (self, baseline) {
+                    #(#arms)*
+                    #fallback
+                }
+            }
+        }
+
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "IsBaseline cannot be derived for unions",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #crate_path::IsBaseline for #name #ty_generics #where_clause {
+            fn matches_baseline(&self, baseline: &Self) -> bool {
+                #body
+            }
+        }
+    })
+}
+
+/// Returns patterns binding the fields of `self` and `baseline`, and the
+/// expression comparing them.
+fn compare(
+    crate_path: &TokenStream,
+    path: TokenStream,
+    fields: &Fields,
+) -> (TokenStream, TokenStream, TokenStream) {
+    let lhs = bindings(fields, "__v");
+    let rhs = bindings(fields, "__b");
+
+    let checks = lhs
+        .iter()
+        .zip(&rhs)
+        .map(|(v, b)| quote!(#crate_path::IsBaseline::matches_baseline(#v, #b)));
+    let checks = quote!(true #(&& #checks)*);
+
+    (
+        pattern(&path, fields, &lhs),
+        pattern(&path, fields, &rhs),
+        checks,
+    )
+}
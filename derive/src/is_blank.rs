@@ -0,0 +1,119 @@
+use crate::utils::{crate_path, default_variant};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Ident, Index, LitStr, Member};
+
+#[derive(Default)]
+struct Options {
+    strict: bool,
+    trim: Option<Ident>,
+}
+
+/// Parses `#[is_blank(strict)]` and `#[is_blank(trim = "...")]`.
+fn parse_options(attrs: &[Attribute]) -> syn::Result<Options> {
+    let mut opts = Options::default();
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("is_blank")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("strict") {
+                opts.strict = true;
+            } else if meta.path.is_ident("trim") {
+                let lit = meta.value()?.parse::<LitStr>()?;
+                let variant = match lit.value().as_str() {
+                    "unicode" => "Unicode",
+                    "ascii" => "Ascii",
+                    "never" => "Never",
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            "expected `unicode`, `ascii` or `never`",
+                        ));
+                    }
+                };
+                opts.trim = Some(Ident::new(variant, lit.span()));
+            } else {
+                return Err(
+                    meta.error("unsupported is_blank attribute, expected `strict` or `trim`")
+                );
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(opts)
+}
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let crate_path = crate_path(name.span());
+    let container = parse_options(&input.attrs)?;
+
+    let body = match &input.data {
+        Data::Struct(data_struct) => {
+            let checks = data_struct
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, f)| {
+                    let member = match &f.ident {
+                        Some(ident) => Member::Named(ident.clone()),
+                        None => Member::Unnamed(Index::from(i)),
+                    };
+                    let opts = parse_options(&f.attrs)?;
+                    Ok(if container.strict || opts.strict {
+                        quote!(#crate_path::IsDefault::is_default(&self.#member))
+                    } else if let Some(trim) = &opts.trim {
+                        quote! {
+                            #crate_path::IsBlank::is_blank_with(&self.#member, #crate_path::Trim::#trim)
+                        }
+                    } else {
+                        quote!(#crate_path::IsBlank::is_blank_with(&self.#member, trim))
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            // A container `trim` overrides the one passed in.
+            let trim = container
+                .trim
+                .as_ref()
+                .map(|trim| quote!(let trim = #crate_path::Trim::#trim;));
+            quote! {
+                let _ = trim;
+                #trim
+                true #(&& #checks)*
+            }
+        }
+
+        Data::Enum(enum_data) => match default_variant(enum_data)? {
+            Some(ident) => quote! {
+                let _ = trim;
+                matches!(self, #name::#ident)
+            },
+            None => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "IsBlank for enums requires a #[default] or #[is_default] unit variant",
+                ));
+            }
+        },
+
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "IsBlank cannot be derived for unions",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #crate_path::IsBlank for #name #ty_generics #where_clause {
+            fn is_blank(&self) -> bool {
+                #crate_path::IsBlank::is_blank_with(self, #crate_path::Trim::Unicode)
+            }
+
+            fn is_blank_with(&self, trim: #crate_path::Trim) -> bool {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,158 @@
+use crate::utils::{FieldAttrs, crate_path, default_variant};
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{Attribute, Data, DeriveInput, Fields, Index, LitStr, Member};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let crate_path = crate_path(name.span());
+
+    let mut versioned = Vec::new();
+    match &input.data {
+        Data::Struct(data_struct) => {
+            for (i, field) in data_struct.fields.iter().enumerate() {
+                if let Some(since) = FieldAttrs::parse(&field.attrs)?.since {
+                    let member = match &field.ident {
+                        Some(ident) => Member::Named(ident.clone()),
+                        None => Member::Unnamed(Index::from(i)),
+                    };
+                    versioned.push((member, version(&crate_path, &since)));
+                }
+            }
+        }
+        Data::Enum(enum_data) => {
+            for field in enum_data.variants.iter().flat_map(|v| &v.fields) {
+                if let Some(since) = FieldAttrs::parse(&field.attrs)?.since {
+                    return Err(syn::Error::new_spanned(
+                        since,
+                        "`since` is only supported on struct fields",
+                    ));
+                }
+            }
+        }
+        Data::Union(_) => {}
+    }
+
+    let body = if is_zeroed(&input.attrs)? {
+        quote!(#crate_path::IsZeroBytes::is_default_bytes(self))
+    } else {
+        match input.data {
+            Data::Struct(data_struct) => match data_struct.fields {
+                Fields::Named(fields_named) => {
+                    let checks = fields_named.named.iter().map(|f| {
+                        let name = &f.ident;
+                        quote! {
+                            #crate_path::IsDefault::is_default(&self.#name)
+                        }
+                    });
+                    quote! {
+                        #(#checks)&&*
+                    }
+                }
+                Fields::Unnamed(fields_unnamed) => {
+                    let checks = fields_unnamed.unnamed.iter().enumerate().map(|(i, _)| {
+                        let index = syn::Index::from(i);
+                        quote! {
+                            #crate_path::IsDefault::is_default(&self.#index)
+                        }
+                    });
+                    quote! {
+                        #(#checks)&&*
+                    }
+                }
+                Fields::Unit => {
+                    quote!(true)
+                }
+            },
+
+            Data::Enum(enum_data) => match default_variant(&enum_data)? {
+                Some(ident) => quote!(matches!(self, #name::#ident)),
+                None => quote!(self == &Default::default()),
+            },
+
+            Data::Union(_) => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "IsDefault for unions requires #[is_default(zeroed)]",
+                ));
+            }
+        }
+    };
+
+    let versions = (!versioned.is_empty()).then(|| {
+        let raises = versioned.iter().map(|(member, since)| {
+            quote! {
+                if !#crate_path::IsDefault::is_default(&self.#member) {
+                    version = ::core::cmp::max(version, #since);
+                }
+            }
+        });
+        let checks = versioned.iter().map(|(member, since)| {
+            quote! {
+                (#since <= version || #crate_path::IsDefault::is_default(&self.#member))
+            }
+        });
+
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Returns the highest `since` version among non-default fields.
+                #[allow(dead_code)]
+                pub fn requires_version(&self) -> #crate_path::Version {
+                    let mut version = #crate_path::Version::new(0, 0, 0);
+                    #(#raises)*
+                    version
+                }
+
+                /// Returns `true` if all fields introduced after `version` are default.
+                #[allow(dead_code)]
+                pub fn is_default_since(&self, version: #crate_path::Version) -> bool {
+                    #(#checks)&&*
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #crate_path::IsDefault for #name #ty_generics #where_clause {
+            fn is_default(&self) -> bool {
+                #body
+            }
+        }
+
+        #versions
+    })
+}
+
+/// Returns a constant `Version` parsed from `since`, which fails to compile
+/// if it is invalid.
+fn version(crate_path: &TokenStream, since: &LitStr) -> TokenStream {
+    quote_spanned! {since.span()=>
+        const {
+            match #crate_path::Version::parse(#since) {
+                ::core::result::Result::Ok(version) => version,
+                ::core::result::Result::Err(_) => {
+                    ::core::panic!("invalid version, expected `major[.minor[.patch]]`")
+                }
+            }
+        }
+    }
+}
+
+/// Returns `true` if the type has `#[is_default(zeroed)]`.
+fn is_zeroed(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut zeroed = false;
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("is_default")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("zeroed") {
+                zeroed = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported is_default attribute, expected `zeroed`"))
+            }
+        })?;
+    }
+
+    Ok(zeroed)
+}
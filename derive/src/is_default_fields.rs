@@ -0,0 +1,90 @@
+use crate::utils::{FieldAttrs, crate_path};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::BTreeMap;
+use syn::{Data, DeriveInput, Index, Member, ext::IdentExt};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let crate_path = crate_path(name.span());
+
+    let fields = match &input.data {
+        Data::Struct(data_struct) => &data_struct.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "IsDefaultFields can only be derived for structs",
+            ));
+        }
+    };
+
+    let mut at_arms = Vec::with_capacity(fields.len());
+    let mut except_checks = Vec::with_capacity(fields.len());
+    let mut groups: BTreeMap<String, Vec<TokenStream>> = BTreeMap::new();
+
+    for (i, field) in fields.iter().enumerate() {
+        let opts = FieldAttrs::parse(&field.attrs)?;
+        let (member, field_name) = match &field.ident {
+            Some(ident) => (Member::Named(ident.clone()), ident.unraw().to_string()),
+            None => (Member::Unnamed(Index::from(i)), i.to_string()),
+        };
+        let check = quote!(#crate_path::IsDefault::is_default(&self.#member));
+
+        let nested = if opts.nested {
+            quote!(#crate_path::IsDefaultFields::is_default_at(&self.#member, rest))
+        } else {
+            quote!(::core::option::Option::None)
+        };
+        at_arms.push(quote! {
+            #field_name => match rest {
+                ::core::option::Option::None => ::core::option::Option::Some(#check),
+                ::core::option::Option::Some(rest) => #nested,
+            },
+        });
+
+        except_checks.push(quote! {
+            (fields.contains(&#field_name) || #check)
+        });
+
+        for group in opts.groups {
+            groups.entry(group).or_default().push(check.clone());
+        }
+    }
+
+    let group_arms = groups.iter().map(|(group, checks)| {
+        quote! {
+            #group => #(#checks)&&*,
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #crate_path::IsDefaultFields for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn is_default_at(&self, path: &str) -> ::core::option::Option<bool> {
+                let (head, rest) = match path.split_once('.') {
+                    ::core::option::Option::Some((head, rest)) => {
+                        (head, ::core::option::Option::Some(rest))
+                    }
+                    ::core::option::Option::None => (path, ::core::option::Option::None),
+                };
+                match head {
+                    #(#at_arms)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            fn is_group_default(&self, group: &str) -> bool {
+                match group {
+                    #(#group_arms)*
+                    _ => true,
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn is_default_except(&self, fields: &[&str]) -> bool {
+                true #(&& #except_checks)*
+            }
+        }
+    })
+}
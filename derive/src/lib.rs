@@ -1,97 +1,203 @@
 use proc_macro::TokenStream;
-use proc_macro_crate::{FoundCrate, crate_name};
-use quote::quote;
-use syn::{Data, DeriveInput, Fields, parse_macro_input};
+use syn::{DeriveInput, parse_macro_input};
+
+mod args;
+mod const_default;
+mod fingerprint;
+mod is_baseline;
+mod is_blank;
+mod is_default;
+mod is_default_fields;
+mod non_default_debug;
+mod normalize;
+mod reset_default;
+mod skip_defaults;
+mod sparse;
+mod utils;
+mod zero_bytes;
 
 /// Derive macro generating an impl of the trait `IsDefault`.
+///
+/// If any struct field has `#[is_default(since = "x.y")]`, it also generates
+/// inherent `requires_version(&self)` and `is_default_since(&self, version)`
+/// methods. Versions are parsed at compile time by `Version::parse`, and
+/// `since` is rejected on enum variant fields.
 #[proc_macro_derive(IsDefault, attributes(is_default, default))]
 pub fn derive_is_default(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    let name = &input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
-
-    let crate_path = match crate_name("is_default") {
-        Ok(FoundCrate::Itself) => quote!(::is_default),
-        Ok(FoundCrate::Name(found)) => {
-            let ident = syn::Ident::new(&found, name.span());
-            quote!(::#ident)
-        }
-        Err(_) => quote!(::is_default),
-    };
-
-    let body = match input.data {
-        Data::Struct(data_struct) => match data_struct.fields {
-            Fields::Named(fields_named) => {
-                let checks = fields_named.named.iter().map(|f| {
-                    let name = &f.ident;
-                    quote! {
-                        #crate_path::IsDefault::is_default(&self.#name)
-                    }
-                });
-                quote! {
-                    #(#checks)&&*
-                }
-            }
-            Fields::Unnamed(fields_unnamed) => {
-                let checks = fields_unnamed.unnamed.iter().enumerate().map(|(i, _)| {
-                    let index = syn::Index::from(i);
-                    quote! {
-                        #crate_path::IsDefault::is_default(&self.#index)
-                    }
-                });
-                quote! {
-                    #(#checks)&&*
-                }
-            }
-            Fields::Unit => {
-                quote!(true)
-            }
-        },
-
-        Data::Enum(enum_data) => {
-            let mut default_variant_ident = None;
-
-            for variant in &enum_data.variants {
-                let has_default_attr = variant.attrs.iter().any(|attr| {
-                    let path = attr.path();
-                    path.is_ident("default") || path.is_ident("is_default")
-                });
-
-                if has_default_attr {
-                    if !matches!(variant.fields, Fields::Unit) {
-                        return syn::Error::new_spanned(
-                            &variant.ident,
-                            "the #[default] or #[is_default] attribute may only be used on unit enum variants",
-                        )
-                        .to_compile_error()
-                        .into();
-                    }
-
-                    default_variant_ident = Some(&variant.ident);
-                    break;
-                }
-            }
-
-            match default_variant_ident {
-                Some(ident) => quote!(matches!(self, #name::#ident)),
-                None => quote!(self == &Default::default()),
-            }
-        }
-
-        Data::Union(_) => {
-            return syn::Error::new_spanned(name, "IsDefault cannot be derived for unions")
-                .to_compile_error()
-                .into();
-        }
-    };
-
-    let expanded = quote! {
-        impl #impl_generics #crate_path::IsDefault for #name #ty_generics #where_clause {
-            fn is_default(&self) -> bool {
-                #body
-            }
-        }
-    };
-
-    TokenStream::from(expanded)
+    is_default::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro generating an impl of the trait `ResetDefault`.
+///
+/// For structs, it also generates an inherent `reset_field(&mut self, name: &str) -> bool`
+/// method, which resets a single field by name.
+#[proc_macro_derive(ResetDefault, attributes(is_default, default))]
+pub fn derive_reset_default(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    reset_default::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro generating an impl of the trait `Debug` that omits default fields.
+///
+/// Fields for which `IsDefault::is_default` returns `true` are skipped, and the
+/// output ends with `..` if any field was skipped.
+#[proc_macro_derive(NonDefaultDebug)]
+pub fn derive_non_default_debug(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    non_default_debug::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Attribute macro adding `#[serde(skip_serializing_if = "is_default::is_default")]`
+/// to every field that doesn't already skip serialization.
+///
+/// With `#[skip_defaults(default)]`, it also adds `#[serde(default)]` to every
+/// field without one. A field can opt out with `#[skip_defaults(keep)]`.
+///
+/// It must be placed before `#[derive(Serialize)]`.
+#[proc_macro_attribute]
+pub fn skip_defaults(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    skip_defaults::expand(args.into(), input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro generating an impl of the trait `SparseEncode`.
+///
+/// Field indices can be pinned with `#[sparse(index = N)]`.
+#[proc_macro_derive(SparseEncode, attributes(sparse))]
+pub fn derive_sparse_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    sparse::derive_encode(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro generating an impl of the trait `SparseDecode`.
+///
+/// Field indices can be pinned with `#[sparse(index = N)]`.
+#[proc_macro_derive(SparseDecode, attributes(sparse))]
+pub fn derive_sparse_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    sparse::derive_decode(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro generating an impl of the trait `ToArgs`.
+///
+/// Supports `#[to_args(prefix = "...")]` on the type, and `#[to_args(name = "...")]`,
+/// `#[to_args(flatten)]` and `#[to_args(skip)]` on fields.
+#[proc_macro_derive(ToArgs, attributes(to_args))]
+pub fn derive_to_args(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    args::derive(args::Kind::Args, input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro generating an impl of the trait `ToEnv`.
+///
+/// Supports `#[to_env(prefix = "...")]` on the type, and `#[to_env(name = "...")]`,
+/// `#[to_env(flatten)]` and `#[to_env(skip)]` on fields.
+#[proc_macro_derive(ToEnv, attributes(to_env))]
+pub fn derive_to_env(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    args::derive(args::Kind::Env, input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro generating an impl of the trait `DefaultInsensitiveHash`.
+///
+/// Default fields are skipped and the others are tagged by name. Supports
+/// `#[fingerprint(name = "...")]` and `#[fingerprint(skip)]` on fields.
+#[proc_macro_derive(DefaultInsensitiveHash, attributes(fingerprint))]
+pub fn derive_default_insensitive_hash(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    fingerprint::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro generating an impl of the trait `IsDefaultFields`.
+///
+/// Supports `#[is_default(group = "...")]` and `#[is_default(nested)]` on
+/// fields.
+#[proc_macro_derive(IsDefaultFields, attributes(is_default))]
+pub fn derive_is_default_fields(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    is_default_fields::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro generating an impl of the trait `IsBaseline`.
+///
+/// Fields are compared with their own `IsBaseline::matches_baseline`.
+#[proc_macro_derive(IsBaseline)]
+pub fn derive_is_baseline(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    is_baseline::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro generating an impl of the trait `ConstDefault`.
+///
+/// Structs whose fields are all supported by `is_default_const!`, and enums,
+/// also get an inherent `const fn is_default_const(&self) -> bool`.
+#[proc_macro_derive(ConstDefault, attributes(is_default, default))]
+pub fn derive_const_default(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    const_default::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro generating an impl of the unsafe trait `IsZeroBytes`.
+///
+/// Requires `#[repr(C)]` or `#[repr(transparent)]`, fields implementing
+/// `IsZeroBytes`, and no padding, which is checked at compile time.
+#[proc_macro_derive(IsZeroBytes)]
+pub fn derive_is_zero_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    zero_bytes::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro generating an impl of the trait `NormalizeDefaults`.
+///
+/// Fields are normalized recursively. `#[normalize(collapse)]` turns
+/// `Some(default)` into `None`, `#[normalize(trim)]` removes trailing default
+/// elements from a `Vec`, and `#[normalize(skip)]` leaves a field as is.
+#[proc_macro_derive(NormalizeDefaults, attributes(normalize))]
+pub fn derive_normalize_defaults(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    normalize::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro generating an impl of the trait `IsBlank`.
+///
+/// Structs are blank if all fields are blank. Fields, or the whole struct,
+/// marked `#[is_blank(strict)]` use `IsDefault` instead, and
+/// `#[is_blank(trim = "unicode" | "ascii" | "never")]` chooses which
+/// whitespace strings in them may hold. Enums are blank if they are the
+/// `#[default]` or `#[is_default]` variant.
+#[proc_macro_derive(IsBlank, attributes(is_blank, is_default, default))]
+pub fn derive_is_blank(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    is_blank::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
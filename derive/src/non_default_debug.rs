@@ -0,0 +1,107 @@
+use crate::utils::{bindings, crate_path, pattern};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Ident, ext::IdentExt};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let crate_path = crate_path(name.span());
+
+    let body = match &input.data {
+        Data::Struct(data_struct) => {
+            let bindings = bindings(&data_struct.fields, "__f");
+            let fmt = fmt_fields(&crate_path, &name.unraw(), &data_struct.fields, &bindings);
+            let pattern = pattern(&quote!(#name), &data_struct.fields, &bindings);
+            quote! {
+                let #pattern = self;
+                #fmt
+            }
+        }
+
+        Data::Enum(enum_data) => {
+            let arms = enum_data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let bindings = bindings(&variant.fields, "__f");
+                let fmt = fmt_fields(&crate_path, &ident.unraw(), &variant.fields, &bindings);
+                let pattern = pattern(&quote!(#name::#ident), &variant.fields, &bindings);
+                quote! {
+                    #pattern => { #fmt }
+                }
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "NonDefaultDebug cannot be derived for unions",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::core::fmt::Debug for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #body
+            }
+        }
+    })
+}
+
+fn fmt_fields(
+    crate_path: &TokenStream,
+    name: &Ident,
+    fields: &Fields,
+    bindings: &[Ident],
+) -> TokenStream {
+    let name = name.to_string();
+
+    let (builder, pushes) = match fields {
+        Fields::Named(fields_named) => {
+            let pushes = fields_named
+                .named
+                .iter()
+                .zip(bindings)
+                .map(|(f, binding)| {
+                    let field_name = f.ident.as_ref().map(|i| i.unraw().to_string());
+                    quote!(builder.field(#field_name, #binding);)
+                })
+                .collect::<Vec<_>>();
+            (quote!(f.debug_struct(#name)), pushes)
+        }
+        Fields::Unnamed(_) => {
+            let pushes = bindings
+                .iter()
+                .map(|binding| quote!(builder.field(#binding);))
+                .collect();
+            (quote!(f.debug_tuple(#name)), pushes)
+        }
+        Fields::Unit => return quote!(f.write_str(#name)),
+    };
+
+    let checks = bindings.iter().zip(pushes).map(|(binding, push)| {
+        quote! {
+            if #crate_path::IsDefault::is_default(#binding) {
+                omitted = true;
+            } else {
+                #push
+            }
+        }
+    });
+
+    quote! {
+        let mut builder = #builder;
+        let mut omitted = false;
+        #(#checks)*
+        if omitted {
+            builder.finish_non_exhaustive()
+        } else {
+            builder.finish()
+        }
+    }
+}
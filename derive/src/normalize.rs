@@ -0,0 +1,110 @@
+use crate::utils::{bindings, crate_path, pattern};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Fields, Ident};
+
+/// How a field is normalized.
+enum Mode {
+    Recurse,
+    Collapse,
+    Trim,
+    Skip,
+}
+
+fn field_mode(attrs: &[Attribute]) -> syn::Result<Mode> {
+    let mut mode = Mode::Recurse;
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("normalize")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("collapse") {
+                mode = Mode::Collapse;
+            } else if meta.path.is_ident("trim") {
+                mode = Mode::Trim;
+            } else if meta.path.is_ident("skip") {
+                mode = Mode::Skip;
+            } else {
+                return Err(meta.error(
+                    "unsupported normalize attribute, expected `collapse`, `trim` or `skip`",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(mode)
+}
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let crate_path = crate_path(name.span());
+
+    let body = match &input.data {
+        Data::Struct(data_struct) => {
+            let bindings = bindings(&data_struct.fields, "__f");
+            let steps = normalize_fields(&crate_path, &data_struct.fields, &bindings)?;
+            let pattern = pattern(&quote!(#name), &data_struct.fields, &bindings);
+            quote! {
+                let #pattern = self;
+                #steps
+            }
+        }
+
+        Data::Enum(enum_data) => {
+            let arms = enum_data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let bindings = bindings(&variant.fields, "__f");
+                    let steps = normalize_fields(&crate_path, &variant.fields, &bindings)?;
+                    let pattern = pattern(&quote!(#name::#ident), &variant.fields, &bindings);
+                    Ok(quote! {
+                        #pattern => { #steps }
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "NormalizeDefaults cannot be derived for unions",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #crate_path::NormalizeDefaults for #name #ty_generics #where_clause {
+            fn normalize_defaults(&mut self) {
+                #body
+            }
+        }
+    })
+}
+
+fn normalize_fields(
+    crate_path: &TokenStream,
+    fields: &Fields,
+    bindings: &[Ident],
+) -> syn::Result<TokenStream> {
+    let mut steps = Vec::with_capacity(bindings.len());
+
+    for (field, binding) in fields.iter().zip(bindings) {
+        steps.push(match field_mode(&field.attrs)? {
+            Mode::Recurse => {
+                quote!(#crate_path::NormalizeDefaults::normalize_defaults(#binding);)
+            }
+            Mode::Collapse => quote!(#crate_path::normalize::collapse_option(#binding);),
+            Mode::Trim => quote!(#crate_path::normalize::trim_trailing(#binding);),
+            Mode::Skip => quote!(let _ = #binding;),
+        });
+    }
+
+    Ok(quote!(#(#steps)*))
+}
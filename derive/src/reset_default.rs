@@ -0,0 +1,83 @@
+use crate::utils::{crate_path, default_variant};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Index, Member, ext::IdentExt};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let crate_path = crate_path(name.span());
+
+    let (body, reset_field) = match input.data {
+        Data::Struct(data_struct) => {
+            let members: Vec<(Member, String)> = match data_struct.fields {
+                Fields::Named(fields_named) => fields_named
+                    .named
+                    .into_iter()
+                    .filter_map(|f| f.ident)
+                    .map(|ident| {
+                        let field_name = ident.unraw().to_string();
+                        (Member::Named(ident), field_name)
+                    })
+                    .collect(),
+                Fields::Unnamed(fields_unnamed) => (0..fields_unnamed.unnamed.len())
+                    .map(|i| (Member::Unnamed(Index::from(i)), i.to_string()))
+                    .collect(),
+                Fields::Unit => Vec::new(),
+            };
+
+            let resets = members.iter().map(|(member, _)| {
+                quote! {
+                    #crate_path::ResetDefault::reset_default(&mut self.#member);
+                }
+            });
+            let arms = members.iter().map(|(member, field_name)| {
+                quote! {
+                    #field_name => {
+                        #crate_path::ResetDefault::reset_default(&mut self.#member);
+                        true
+                    }
+                }
+            });
+
+            let reset_field = quote! {
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Resets the field named `name` to its default value.
+                    ///
+                    /// Returns `false` if there is no such field.
+                    #[allow(dead_code)]
+                    pub fn reset_field(&mut self, name: &str) -> bool {
+                        match name {
+                            #(#arms)*
+                            _ => false,
+                        }
+                    }
+                }
+            };
+
+            (quote!(#(#resets)*), reset_field)
+        }
+
+        Data::Enum(enum_data) => match default_variant(&enum_data)? {
+            Some(ident) => (quote!(*self = #name::#ident;), quote!()),
+            None => (quote!(*self = Default::default();), quote!()),
+        },
+
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "ResetDefault cannot be derived for unions",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #crate_path::ResetDefault for #name #ty_generics #where_clause {
+            fn reset_default(&mut self) {
+                #body
+            }
+        }
+
+        #reset_field
+    })
+}
@@ -0,0 +1,115 @@
+use crate::utils::crate_path;
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Field, Fields, Meta, parse::Parser, parse_quote};
+
+pub fn expand(args: TokenStream, input: DeriveInput) -> syn::Result<TokenStream> {
+    let mut with_default = false;
+    syn::meta::parser(|meta| {
+        if meta.path.is_ident("default") {
+            with_default = true;
+            Ok(())
+        } else {
+            Err(meta.error("unsupported skip_defaults argument, expected `default`"))
+        }
+    })
+    .parse2(args)?;
+
+    let mut input = input;
+    let skip_if = format!("{}::is_default", crate_path(input.ident.span())).replace(' ', "");
+
+    match &mut input.data {
+        Data::Struct(data_struct) => {
+            update_fields(&mut data_struct.fields, &skip_if, with_default)?;
+        }
+        Data::Enum(data_enum) => {
+            for variant in &mut data_enum.variants {
+                if let Fields::Named(_) = variant.fields {
+                    update_fields(&mut variant.fields, &skip_if, with_default)?;
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "skip_defaults cannot be used on unions",
+            ));
+        }
+    }
+
+    Ok(quote!(#input))
+}
+
+fn update_fields(fields: &mut Fields, skip_if: &str, with_default: bool) -> syn::Result<()> {
+    let Fields::Named(fields_named) = fields else {
+        return Err(syn::Error::new_spanned(
+            fields,
+            "skip_defaults may only be used on structs with named fields",
+        ));
+    };
+
+    for field in &mut fields_named.named {
+        update_field(field, skip_if, with_default)?;
+    }
+
+    Ok(())
+}
+
+fn update_field(field: &mut Field, skip_if: &str, with_default: bool) -> syn::Result<()> {
+    let mut keep = false;
+    let mut attrs = Vec::with_capacity(field.attrs.len());
+
+    for attr in field.attrs.drain(..) {
+        if attr.path().is_ident("skip_defaults") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("keep") {
+                    keep = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported skip_defaults argument, expected `keep`"))
+                }
+            })?;
+        } else {
+            attrs.push(attr);
+        }
+    }
+
+    field.attrs = attrs;
+
+    if keep {
+        return Ok(());
+    }
+
+    let keys = serde_keys(&field.attrs);
+    let has = |key: &str| keys.iter().any(|k| k == key);
+
+    if !has("skip") && !has("skip_serializing") && !has("skip_serializing_if") {
+        field
+            .attrs
+            .push(parse_quote!(#[serde(skip_serializing_if = #skip_if)]));
+    }
+
+    if with_default && !has("skip") && !has("skip_deserializing") && !has("default") {
+        field.attrs.push(parse_quote!(#[serde(default)]));
+    }
+
+    Ok(())
+}
+
+/// Returns the top-level keys of all `#[serde(...)]` attributes.
+fn serde_keys(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::List(list) => Some(list.tokens.clone()),
+            _ => None,
+        })
+        .flat_map(|tokens| {
+            tokens.into_iter().filter_map(|tt| match tt {
+                TokenTree::Ident(ident) => Some(ident.to_string()),
+                _ => None,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,152 @@
+use crate::utils::crate_path;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Index, LitInt, Member, Type};
+
+/// The number of field indices the presence bitmap can hold.
+const MAX_INDEX: u32 = 128;
+
+struct SparseField<'a> {
+    index: u32,
+    member: Member,
+    ty: &'a Type,
+}
+
+pub fn derive_encode(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let crate_path = crate_path(name.span());
+    let fields = sparse_fields(&input, "SparseEncode")?;
+
+    let presence = fields.iter().map(|f| {
+        let member = &f.member;
+        let index = f.index;
+        quote! {
+            if !#crate_path::IsDefault::is_default(&self.#member) {
+                presence |= 1u128 << #index;
+            }
+        }
+    });
+    let writes = fields.iter().map(|f| {
+        let member = &f.member;
+        let index = f.index;
+        quote! {
+            if presence & (1u128 << #index) != 0 {
+                #crate_path::sparse::encode_field(&self.#member, out);
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #crate_path::SparseEncode for #name #ty_generics #where_clause {
+            fn sparse_encode(&self, out: &mut ::std::vec::Vec<u8>) {
+                let mut presence = 0u128;
+                #(#presence)*
+                #crate_path::sparse::encode_varint(presence, out);
+                #(#writes)*
+            }
+        }
+    })
+}
+
+pub fn derive_decode(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let crate_path = crate_path(name.span());
+    let fields = sparse_fields(&input, "SparseDecode")?;
+
+    let vars: Vec<_> = (0..fields.len())
+        .map(|i| format_ident!("__f{}", i))
+        .collect();
+
+    let decls = fields.iter().zip(&vars).map(|(f, var)| {
+        let ty = f.ty;
+        quote!(let mut #var: ::core::option::Option<#ty> = ::core::option::Option::None;)
+    });
+    let arms = fields.iter().zip(&vars).map(|(f, var)| {
+        let index = f.index;
+        quote! {
+            #index => #var = ::core::option::Option::Some(#crate_path::sparse::decode_exact(field)?),
+        }
+    });
+    let inits = fields.iter().zip(&vars).map(|(f, var)| {
+        let member = &f.member;
+        quote!(#member: #var.unwrap_or_default())
+    });
+
+    Ok(quote! {
+        impl #impl_generics #crate_path::SparseDecode for #name #ty_generics #where_clause {
+            fn sparse_decode(
+                input: &mut &[u8],
+            ) -> ::core::result::Result<Self, #crate_path::sparse::DecodeError> {
+                let mut presence = #crate_path::sparse::decode_varint(input)?;
+                #(#decls)*
+                while presence != 0 {
+                    let index = presence.trailing_zeros();
+                    presence &= presence - 1;
+                    let field = #crate_path::sparse::decode_field(input)?;
+                    match index {
+                        #(#arms)*
+                        _ => {}
+                    }
+                }
+                ::core::result::Result::Ok(#name { #(#inits),* })
+            }
+        }
+    })
+}
+
+fn sparse_fields<'a>(
+    input: &'a DeriveInput,
+    trait_name: &str,
+) -> syn::Result<Vec<SparseField<'a>>> {
+    let Data::Struct(data_struct) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            format!("{} can only be derived for structs", trait_name),
+        ));
+    };
+
+    let mut fields = Vec::with_capacity(data_struct.fields.len());
+
+    for (i, field) in data_struct.fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        };
+        let mut index = i as u32;
+
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("sparse")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("index") {
+                    index = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported sparse attribute, expected `index`"))
+                }
+            })?;
+        }
+
+        if index >= MAX_INDEX {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!("sparse field index must be less than {}", MAX_INDEX),
+            ));
+        }
+        if fields.iter().any(|f: &SparseField| f.index == index) {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!("duplicate sparse field index {}", index),
+            ));
+        }
+
+        fields.push(SparseField {
+            index,
+            member,
+            ty: &field.ty,
+        });
+    }
+
+    fields.sort_by_key(|f| f.index);
+    Ok(fields)
+}
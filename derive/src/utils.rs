@@ -0,0 +1,95 @@
+use proc_macro_crate::{FoundCrate, crate_name};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Attribute, DataEnum, Fields, Ident, LitStr};
+
+/// Returns the path to the `is_default` crate as seen from the caller.
+pub fn crate_path(span: Span) -> TokenStream {
+    match crate_name("is_default") {
+        Ok(FoundCrate::Itself) => quote!(::is_default),
+        Ok(FoundCrate::Name(found)) => {
+            let ident = Ident::new(&found, span);
+            quote!(::#ident)
+        }
+        Err(_) => quote!(::is_default),
+    }
+}
+
+/// Returns the unit variant marked with `#[default]` or `#[is_default]`, if any.
+pub fn default_variant(data: &DataEnum) -> syn::Result<Option<&Ident>> {
+    for variant in &data.variants {
+        let has_default_attr = variant.attrs.iter().any(|attr| {
+            let path = attr.path();
+            path.is_ident("default") || path.is_ident("is_default")
+        });
+
+        if has_default_attr {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(syn::Error::new_spanned(
+                    &variant.ident,
+                    "the #[default] or #[is_default] attribute may only be used on unit enum variants",
+                ));
+            }
+
+            return Ok(Some(&variant.ident));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Returns one binding `{prefix}{i}` per field, for use with [`pattern`].
+pub fn bindings(fields: &Fields, prefix: &str) -> Vec<Ident> {
+    (0..fields.len())
+        .map(|i| format_ident!("{}{}", prefix, i))
+        .collect()
+}
+
+/// Returns a pattern destructuring `path` with `fields` into `bindings`.
+pub fn pattern(path: &TokenStream, fields: &Fields, bindings: &[Ident]) -> TokenStream {
+    match fields {
+        Fields::Named(fields_named) => {
+            let names = fields_named.named.iter().map(|f| &f.ident);
+            quote!(#path { #(#names: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#bindings),*)),
+        Fields::Unit => quote!(#path),
+    }
+}
+
+/// Options from `#[is_default(...)]` on a field.
+#[derive(Default)]
+pub struct FieldAttrs {
+    /// The version literal from `since = "x.y.z"`, parsed by `Version::parse`
+    /// in the generated code.
+    pub since: Option<LitStr>,
+    /// The groups from `group = "..."`, which may be repeated.
+    pub groups: Vec<String>,
+    /// Whether `nested` is present.
+    pub nested: bool,
+}
+
+impl FieldAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut opts = Self::default();
+
+        for attr in attrs.iter().filter(|a| a.path().is_ident("is_default")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("since") {
+                    opts.since = Some(meta.value()?.parse::<LitStr>()?);
+                } else if meta.path.is_ident("group") {
+                    opts.groups.push(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("nested") {
+                    opts.nested = true;
+                } else {
+                    return Err(meta.error(
+                        "unsupported is_default attribute, expected `since`, `group` or `nested`",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(opts)
+    }
+}
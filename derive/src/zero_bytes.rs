@@ -0,0 +1,88 @@
+use crate::utils::crate_path;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Meta, Token, punctuated::Punctuated};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let crate_path = crate_path(name.span());
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "IsZeroBytes cannot be derived for generic types",
+        ));
+    }
+
+    if !has_stable_repr(&input)? {
+        return Err(syn::Error::new_spanned(
+            name,
+            "IsZeroBytes can only be derived for #[repr(C)] or #[repr(transparent)] types",
+        ));
+    }
+
+    let (types, layout) = match &input.data {
+        Data::Struct(data_struct) => {
+            let types: Vec<_> = data_struct.fields.iter().map(|f| &f.ty).collect();
+            let msg = format!("`{}` has padding", name);
+            let layout = quote! {
+                assert!(
+                    ::core::mem::size_of::<#name>() == 0 #(+ ::core::mem::size_of::<#types>())*,
+                    #msg,
+                );
+            };
+            (types, layout)
+        }
+
+        Data::Union(data_union) => {
+            let types: Vec<_> = data_union.fields.named.iter().map(|f| &f.ty).collect();
+            let msg = format!("every field of `{}` must have the size of the union", name);
+            let layout = quote! {
+                #(
+                    assert!(
+                        ::core::mem::size_of::<#types>() == ::core::mem::size_of::<#name>(),
+                        #msg,
+                    );
+                )*
+            };
+            (types, layout)
+        }
+
+        Data::Enum(_) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "IsZeroBytes cannot be derived for enums",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        const _: () = {
+            fn assert_fields() {
+                fn assert_field<T: #crate_path::IsZeroBytes>() {}
+                #(assert_field::<#types>();)*
+            }
+
+            #layout
+        };
+
+        // SAFETY: all fields are zero bytes when default and have no
+        // padding, and the layout checks above rule out padding between
+        // them.
+        unsafe impl #crate_path::IsZeroBytes for #name {}
+    })
+}
+
+fn has_stable_repr(input: &DeriveInput) -> syn::Result<bool> {
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        if metas
+            .iter()
+            .any(|meta| meta.path().is_ident("C") || meta.path().is_ident("transparent"))
+        {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
@@ -0,0 +1,164 @@
+//! Command-line arguments and environment variables from non-default fields.
+//!
+//! Derived impls emit one entry per non-default field:
+//! - `ToArgs` emits `--field-name=value`, or `--field-name` for `true` bools
+//! - `ToEnv` emits `(FIELD_NAME, value)`
+//!
+//! Names can be changed with `#[to_args(name = "...")]` and `#[to_env(name = "...")]`
+//! on fields, and prefixed with `#[to_args(prefix = "...")]` and
+//! `#[to_env(prefix = "...")]` on the type. Fields marked with `flatten` are
+//! nested structs, whose entries are emitted with the field name and a
+//! separator (`-` or `_`) as a prefix. Fields marked with `skip` are
+//! never emitted.
+
+use std::{
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
+};
+
+/// Converts non-default fields into command-line arguments.
+pub trait ToArgs {
+    /// Appends the arguments of non-default fields to `args`, with `prefix`
+    /// put before every argument name.
+    fn write_args(&self, prefix: &str, args: &mut Vec<OsString>);
+
+    /// Returns the arguments of non-default fields.
+    fn to_args(&self) -> Vec<OsString> {
+        let mut args = Vec::new();
+        self.write_args("", &mut args);
+        args
+    }
+}
+
+/// Converts non-default fields into environment variables.
+pub trait ToEnv {
+    /// Appends the variables of non-default fields to `env`, with `prefix`
+    /// put before every variable name.
+    fn write_env(&self, prefix: &str, env: &mut Vec<(OsString, OsString)>);
+
+    /// Returns the variables of non-default fields.
+    fn to_env(&self) -> Vec<(OsString, OsString)> {
+        let mut env = Vec::new();
+        self.write_env("", &mut env);
+        env
+    }
+}
+
+/// Formats a field value for a command-line argument or environment variable.
+pub trait ArgValue {
+    /// Returns `self` formatted as an argument value.
+    fn to_arg_value(&self) -> OsString;
+}
+
+/// Appends `--{prefix}{name}={value}`, or `--{prefix}{name}` for `None`.
+#[doc(hidden)]
+pub fn push_arg(args: &mut Vec<OsString>, prefix: &str, name: &str, value: Option<OsString>) {
+    let mut arg = OsString::from(format!("--{}{}", prefix, name));
+    if let Some(value) = value {
+        arg.push("=");
+        arg.push(value);
+    }
+    args.push(arg);
+}
+
+/// Appends `({prefix}{name}, value)`.
+#[doc(hidden)]
+pub fn push_env(env: &mut Vec<(OsString, OsString)>, prefix: &str, name: &str, value: OsString) {
+    env.push((OsString::from(format!("{}{}", prefix, name)), value));
+}
+
+macro_rules! display_impl {
+    ($($t:ty),*) => {
+        $(
+            impl ArgValue for $t {
+                /// Formats self with `Display`.
+                #[inline]
+                fn to_arg_value(&self) -> OsString {
+                    OsString::from(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+display_impl!(bool, char, f32, f64);
+display_impl!(i8, i16, i32, i64, i128, isize);
+display_impl!(u8, u16, u32, u64, u128, usize);
+display_impl!(str, String);
+
+macro_rules! os_str_impl {
+    ($($t:ty),*) => {
+        $(
+            impl ArgValue for $t {
+                /// Returns self as an `OsString`.
+                #[inline]
+                fn to_arg_value(&self) -> OsString {
+                    AsRef::<OsStr>::as_ref(self).to_os_string()
+                }
+            }
+        )*
+    };
+}
+
+os_str_impl!(OsStr, OsString, Path, PathBuf);
+
+impl<T> ArgValue for Option<T>
+where
+    T: ArgValue,
+{
+    /// Returns the inner value, or an empty string for `None`.
+    #[inline]
+    fn to_arg_value(&self) -> OsString {
+        self.as_ref().map(T::to_arg_value).unwrap_or_default()
+    }
+}
+
+impl<T> ArgValue for [T]
+where
+    T: ArgValue,
+{
+    /// Returns the elements separated by commas.
+    fn to_arg_value(&self) -> OsString {
+        let mut value = OsString::new();
+        for (i, v) in self.iter().enumerate() {
+            if i > 0 {
+                value.push(",");
+            }
+            value.push(v.to_arg_value());
+        }
+        value
+    }
+}
+
+impl<T> ArgValue for Vec<T>
+where
+    T: ArgValue,
+{
+    /// Returns the elements separated by commas.
+    #[inline]
+    fn to_arg_value(&self) -> OsString {
+        self.as_slice().to_arg_value()
+    }
+}
+
+impl<T> ArgValue for Box<T>
+where
+    T: ArgValue + ?Sized,
+{
+    /// Returns the inner value.
+    #[inline]
+    fn to_arg_value(&self) -> OsString {
+        (**self).to_arg_value()
+    }
+}
+
+impl<T> ArgValue for &T
+where
+    T: ArgValue + ?Sized,
+{
+    /// Returns the referenced value.
+    #[inline]
+    fn to_arg_value(&self) -> OsString {
+        (**self).to_arg_value()
+    }
+}
@@ -0,0 +1,231 @@
+use core::marker::PhantomData;
+
+/// Types with a default value usable in `const` contexts.
+///
+/// `DEFAULT` must equal `Default::default()` for types that implement both.
+pub trait ConstDefault: Sized {
+    /// The default value.
+    const DEFAULT: Self;
+}
+
+macro_rules! const_default_impl {
+    ($($t:ty => $v:expr),* $(,)?) => {
+        $(
+            impl ConstDefault for $t {
+                const DEFAULT: Self = $v;
+            }
+        )*
+    };
+}
+
+const_default_impl!(
+    () => (),
+    bool => false,
+    char => '\x00',
+    f32 => 0.0,
+    f64 => 0.0,
+    i8 => 0,
+    i16 => 0,
+    i32 => 0,
+    i64 => 0,
+    i128 => 0,
+    isize => 0,
+    u8 => 0,
+    u16 => 0,
+    u32 => 0,
+    u64 => 0,
+    u128 => 0,
+    usize => 0,
+    &str => "",
+);
+
+#[cfg(feature = "f16")]
+const_default_impl!(f16 => 0.0);
+
+#[cfg(feature = "f128")]
+const_default_impl!(f128 => 0.0);
+
+#[cfg(feature = "ascii_char")]
+const_default_impl!(core::ascii::Char => core::ascii::Char::Null);
+
+impl<T> ConstDefault for &[T] {
+    const DEFAULT: Self = &[];
+}
+
+impl<T> ConstDefault for Option<T> {
+    const DEFAULT: Self = None;
+}
+
+impl<T> ConstDefault for PhantomData<T>
+where
+    T: ?Sized,
+{
+    const DEFAULT: Self = PhantomData;
+}
+
+impl<T, const N: usize> ConstDefault for [T; N]
+where
+    T: ConstDefault,
+{
+    const DEFAULT: Self = [T::DEFAULT; N];
+}
+
+macro_rules! tuple_impls {
+    ($($T:ident)+) => {
+        impl<$($T: ConstDefault),+> ConstDefault for ($($T,)+) {
+            const DEFAULT: Self = ($($T::DEFAULT,)+);
+        }
+    };
+}
+
+tuple_impls!(A);
+tuple_impls!(A B);
+tuple_impls!(A B C);
+tuple_impls!(A B C D);
+tuple_impls!(A B C D E);
+tuple_impls!(A B C D E F);
+tuple_impls!(A B C D E F G);
+tuple_impls!(A B C D E F G H);
+tuple_impls!(A B C D E F G H I);
+tuple_impls!(A B C D E F G H I J);
+tuple_impls!(A B C D E F G H I J K);
+tuple_impls!(A B C D E F G H I J K L);
+
+/// Returns `true` if a primitive value is default, in `const` contexts.
+///
+/// Supports `bool`, `char`, integers, floats, `&str`, `()`, options of any
+/// type, and arrays of the scalar types above. For other types, use the
+/// `is_default_const` method generated by the `ConstDefault` derive.
+///
+/// ```
+/// use is_default::is_default_const;
+///
+/// const fn is_unset(port: u16, host: &str) -> bool {
+///     is_default_const!(port) && is_default_const!(host)
+/// }
+///
+/// const _: () = assert!(is_unset(0, ""));
+/// const _: () = assert!(!is_unset(80, ""));
+/// ```
+#[macro_export]
+macro_rules! is_default_const {
+    ($e:expr) => {
+        $crate::Probe(&$e).is_default_const()
+    };
+}
+
+/// Dispatches `is_default_const!` to a `const fn` per type.
+#[doc(hidden)]
+pub struct Probe<'a, T: ?Sized>(pub &'a T);
+
+macro_rules! probe_impl {
+    ($($t:ty),*) => {
+        $(
+            impl Probe<'_, $t> {
+                #[inline]
+                pub const fn is_default_const(&self) -> bool {
+                    *self.0 == 0 as $t
+                }
+            }
+
+            impl<const N: usize> Probe<'_, [$t; N]> {
+                pub const fn is_default_const(&self) -> bool {
+                    let mut i = 0;
+                    while i < N {
+                        if self.0[i] != 0 as $t {
+                            return false;
+                        }
+                        i += 1;
+                    }
+                    true
+                }
+            }
+        )*
+    };
+}
+
+probe_impl!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+#[cfg(feature = "f16")]
+probe_impl!(f16);
+
+#[cfg(feature = "f128")]
+probe_impl!(f128);
+
+impl Probe<'_, bool> {
+    #[inline]
+    pub const fn is_default_const(&self) -> bool {
+        !*self.0
+    }
+}
+
+impl<const N: usize> Probe<'_, [bool; N]> {
+    pub const fn is_default_const(&self) -> bool {
+        let mut i = 0;
+        while i < N {
+            if self.0[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+impl Probe<'_, char> {
+    #[inline]
+    pub const fn is_default_const(&self) -> bool {
+        *self.0 == '\x00'
+    }
+}
+
+impl<const N: usize> Probe<'_, [char; N]> {
+    pub const fn is_default_const(&self) -> bool {
+        let mut i = 0;
+        while i < N {
+            if self.0[i] != '\x00' {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+impl Probe<'_, &str> {
+    #[inline]
+    pub const fn is_default_const(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Probe<'_, str> {
+    #[inline]
+    pub const fn is_default_const(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Probe<'_, ()> {
+    #[inline]
+    pub const fn is_default_const(&self) -> bool {
+        true
+    }
+}
+
+impl<T> Probe<'_, Option<T>> {
+    #[inline]
+    pub const fn is_default_const(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+#[cfg(feature = "ascii_char")]
+impl Probe<'_, core::ascii::Char> {
+    #[inline]
+    pub const fn is_default_const(&self) -> bool {
+        matches!(self.0, core::ascii::Char::Null)
+    }
+}
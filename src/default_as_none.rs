@@ -0,0 +1,179 @@
+use crate::IsDefault;
+use core::{fmt, mem};
+
+/// An `Option`-like wrapper that stores `None` as `T::default()`.
+///
+/// It has the same size and layout as `T`, so it suits fields where the
+/// default value already means "unset", such as a zero id. The catch is
+/// that `Some(T::default())` cannot be represented and reads as `None`.
+///
+/// With the `serde` feature, it (de)serializes like `Option<T>`. Combine it
+/// with `#[serde(default, skip_serializing_if = "is_default::is_default")]`
+/// to omit `None` fields entirely.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DefaultAsNone<T>(T);
+
+impl<T> DefaultAsNone<T>
+where
+    T: IsDefault + Default,
+{
+    /// Creates a wrapper holding `value`, which is `None` if it is default.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns a wrapper holding `None`.
+    #[inline]
+    pub fn none() -> Self {
+        Self(T::default())
+    }
+
+    /// Returns `true` if the value is not default.
+    #[inline]
+    pub fn is_some(&self) -> bool {
+        !self.0.is_default()
+    }
+
+    /// Returns `true` if the value is default.
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        self.0.is_default()
+    }
+
+    /// Returns a reference to the value, or `None` if it is default.
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        if self.is_none() { None } else { Some(&self.0) }
+    }
+
+    /// Takes the value out, leaving `None` in its place.
+    #[inline]
+    pub fn take(&mut self) -> Option<T> {
+        Self(mem::take(&mut self.0)).into_option()
+    }
+
+    /// Replaces the value with `value`, returning the old one.
+    #[inline]
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        Self(mem::replace(&mut self.0, value)).into_option()
+    }
+
+    /// Maps the value with `f`, or returns `None`.
+    #[inline]
+    pub fn map<U, F>(self, f: F) -> Option<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        self.into_option().map(f)
+    }
+
+    /// Returns the value, or `default` if it is `None`.
+    #[inline]
+    pub fn unwrap_or(self, default: T) -> T {
+        self.into_option().unwrap_or(default)
+    }
+
+    /// Converts into an `Option`.
+    #[inline]
+    pub fn into_option(self) -> Option<T> {
+        if self.is_none() { None } else { Some(self.0) }
+    }
+
+    /// Returns the stored value, which is `T::default()` for `None`.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<Option<T>> for DefaultAsNone<T>
+where
+    T: Default,
+{
+    /// Converts `None` to `T::default()`.
+    #[inline]
+    fn from(value: Option<T>) -> Self {
+        Self(value.unwrap_or_default())
+    }
+}
+
+impl<T> From<DefaultAsNone<T>> for Option<T>
+where
+    T: IsDefault + Default,
+{
+    #[inline]
+    fn from(value: DefaultAsNone<T>) -> Self {
+        value.into_option()
+    }
+}
+
+impl<T> fmt::Debug for DefaultAsNone<T>
+where
+    T: IsDefault + Default + fmt::Debug,
+{
+    /// Formats like `Option<T>`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+#[cfg(not(feature = "via_default_eq"))]
+mod not_via_default_eq {
+    use super::DefaultAsNone;
+    use crate::{IsDefault, ResetDefault};
+
+    impl<T> IsDefault for DefaultAsNone<T>
+    where
+        T: IsDefault,
+    {
+        /// Returns `true` if self is `None`.
+        #[inline]
+        fn is_default(&self) -> bool {
+            self.0.is_default()
+        }
+    }
+
+    impl<T> ResetDefault for DefaultAsNone<T>
+    where
+        T: ResetDefault,
+    {
+        /// Sets self to `None`.
+        #[inline]
+        fn reset_default(&mut self) {
+            self.0.reset_default();
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod feature_serde {
+    use super::DefaultAsNone;
+    use crate::IsDefault;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    impl<T> Serialize for DefaultAsNone<T>
+    where
+        T: IsDefault + Default + Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.get().serialize(serializer)
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for DefaultAsNone<T>
+    where
+        T: Default + Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Option::<T>::deserialize(deserializer).map(Self::from)
+        }
+    }
+}
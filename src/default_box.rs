@@ -0,0 +1,221 @@
+use crate::IsDefault;
+use std::{borrow::Cow, fmt};
+
+/// A heap box that does not allocate for the default value.
+///
+/// It is stored as a nullable pointer, with null meaning `T::default()`,
+/// so it has the size of `Box<T>`. [`get`] returns `None` while the box is
+/// unallocated, and [`get_mut`] allocates on the first write.
+///
+/// [`set`] with a default value releases the allocation. Writes through
+/// [`get_mut`] cannot be tracked, so call [`shrink`] after them to release
+/// it when the value became default.
+///
+/// With the `serde` feature, it (de)serializes as `T`.
+///
+/// [`get`]: DefaultBox::get
+/// [`get_mut`]: DefaultBox::get_mut
+/// [`set`]: DefaultBox::set
+/// [`shrink`]: DefaultBox::shrink
+pub struct DefaultBox<T>(Option<Box<T>>);
+
+impl<T> DefaultBox<T> {
+    /// Returns a box holding `value`, allocating only if it is not default.
+    #[inline]
+    pub fn new(value: T) -> Self
+    where
+        T: IsDefault,
+    {
+        if value.is_default() {
+            Self(None)
+        } else {
+            Self(Some(Box::new(value)))
+        }
+    }
+
+    /// Returns `true` if the value is on the heap.
+    #[inline]
+    pub const fn is_allocated(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Returns a reference to the value, or `None` if it is unallocated.
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        self.0.as_deref()
+    }
+
+    /// Returns the value, borrowed if allocated and `T::default()` otherwise.
+    #[inline]
+    pub fn get_or_default(&self) -> Cow<'_, T>
+    where
+        T: Clone + Default,
+    {
+        match &self.0 {
+            Some(boxed) => Cow::Borrowed(boxed),
+            None => Cow::Owned(T::default()),
+        }
+    }
+
+    /// Returns a mutable reference to the value, allocating
+    /// `T::default()` if it is unallocated.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T
+    where
+        T: Default,
+    {
+        self.0.get_or_insert_with(Box::default)
+    }
+
+    /// Sets the value, releasing the allocation if `value` is default and
+    /// reusing it otherwise.
+    #[inline]
+    pub fn set(&mut self, value: T)
+    where
+        T: IsDefault,
+    {
+        if value.is_default() {
+            self.0 = None;
+        } else if let Some(boxed) = &mut self.0 {
+            **boxed = value;
+        } else {
+            self.0 = Some(Box::new(value));
+        }
+    }
+
+    /// Releases the allocation if the value is default.
+    #[inline]
+    pub fn shrink(&mut self)
+    where
+        T: IsDefault,
+    {
+        if self.0.as_deref().is_some_and(T::is_default) {
+            self.0 = None;
+        }
+    }
+
+    /// Returns the value.
+    #[inline]
+    pub fn into_inner(self) -> T
+    where
+        T: Default,
+    {
+        self.0.map_or_else(T::default, |boxed| *boxed)
+    }
+}
+
+impl<T> Default for DefaultBox<T> {
+    #[inline]
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<T> Clone for DefaultBox<T>
+where
+    T: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for DefaultBox<T>
+where
+    T: PartialEq + Default,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self.get(), other.get()) {
+            (None, None) => true,
+            (Some(v), Some(o)) => v == o,
+            (Some(v), None) | (None, Some(v)) => *v == T::default(),
+        }
+    }
+}
+
+impl<T> Eq for DefaultBox<T> where T: Eq + Default {}
+
+impl<T> From<T> for DefaultBox<T>
+where
+    T: IsDefault,
+{
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> fmt::Debug for DefaultBox<T>
+where
+    T: fmt::Debug + Default,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.get() {
+            Some(value) => fmt::Debug::fmt(value, f),
+            None => fmt::Debug::fmt(&T::default(), f),
+        }
+    }
+}
+
+#[cfg(not(feature = "via_default_eq"))]
+mod not_via_default_eq {
+    use super::DefaultBox;
+    use crate::{IsDefault, ResetDefault};
+
+    impl<T> IsDefault for DefaultBox<T>
+    where
+        T: IsDefault,
+    {
+        /// Returns `true` without dereferencing if the box is unallocated.
+        #[inline]
+        fn is_default(&self) -> bool {
+            self.0.as_deref().is_none_or(T::is_default)
+        }
+    }
+
+    impl<T> ResetDefault for DefaultBox<T>
+    where
+        T: IsDefault,
+    {
+        /// Releases the allocation.
+        #[inline]
+        fn reset_default(&mut self) {
+            self.0 = None;
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod feature_serde {
+    use super::DefaultBox;
+    use crate::IsDefault;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    impl<T> Serialize for DefaultBox<T>
+    where
+        T: Default + Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self.get() {
+                Some(value) => value.serialize(serializer),
+                None => T::default().serialize(serializer),
+            }
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for DefaultBox<T>
+    where
+        T: IsDefault + Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            T::deserialize(deserializer).map(Self::new)
+        }
+    }
+}
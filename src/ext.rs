@@ -0,0 +1,169 @@
+use crate::IsDefault;
+use core::{iter::Filter, slice::Split};
+
+/// Elements checked at once by [`IsDefaultSliceExt`] before looking at
+/// them one by one.
+const CHUNK: usize = 256;
+
+/// Value combinators built on [`IsDefault`].
+pub trait IsDefaultExt: IsDefault {
+    /// Returns `true` if `self` is not default.
+    #[inline]
+    fn is_non_default(&self) -> bool {
+        !self.is_default()
+    }
+
+    /// Returns `None` if `self` is default, otherwise `Some(self)`.
+    #[inline]
+    fn non_default(self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if self.is_default() { None } else { Some(self) }
+    }
+
+    /// Returns `None` if `self` is default, otherwise `Some(&self)`.
+    #[inline]
+    fn non_default_ref(&self) -> Option<&Self> {
+        if self.is_default() { None } else { Some(self) }
+    }
+
+    /// Returns `other` if `self` is default, otherwise `self`.
+    #[inline]
+    fn or_if_default(self, other: Self) -> Self
+    where
+        Self: Sized,
+    {
+        if self.is_default() { other } else { self }
+    }
+
+    /// Returns the result of `f` if `self` is default, otherwise `self`.
+    #[inline]
+    fn or_else_if_default<F>(self, f: F) -> Self
+    where
+        Self: Sized,
+        F: FnOnce() -> Self,
+    {
+        if self.is_default() { f() } else { self }
+    }
+}
+
+impl<T> IsDefaultExt for T where T: IsDefault + ?Sized {}
+
+/// Iterator helpers built on [`IsDefault`].
+pub trait IsDefaultIterExt: Iterator + Sized
+where
+    Self::Item: IsDefault,
+{
+    /// Skips default items.
+    fn filter_non_default(self) -> Filter<Self, fn(&Self::Item) -> bool> {
+        self.filter(|v| !v.is_default())
+    }
+
+    /// Returns `true` if all items are default.
+    fn all_default(mut self) -> bool {
+        self.all(|v| v.is_default())
+    }
+
+    /// Returns the number of non-default items.
+    fn count_non_default(self) -> usize {
+        self.filter(|v| !v.is_default()).count()
+    }
+
+    /// Returns the index of the first non-default item.
+    fn position_non_default(mut self) -> Option<usize> {
+        self.position(|v| !v.is_default())
+    }
+}
+
+impl<I> IsDefaultIterExt for I
+where
+    I: Iterator,
+    I::Item: IsDefault,
+{
+}
+
+/// Slice helpers built on [`IsDefault`].
+///
+/// Runs of default elements are skipped with
+/// [`IsDefault::is_default_slice`], which is fast for primitives.
+pub trait IsDefaultSliceExt<T> {
+    /// Returns `true` if all elements are default.
+    fn all_default(&self) -> bool;
+
+    /// Returns the number of non-default elements.
+    fn count_non_default(&self) -> usize;
+
+    /// Returns the index of the first non-default element.
+    fn position_non_default(&self) -> Option<usize>;
+
+    /// Returns the slice without its trailing default elements.
+    fn trim_trailing_defaults(&self) -> &[T];
+
+    /// Returns an iterator over subslices separated by default elements.
+    fn split_on_default(&self) -> Split<'_, T, fn(&T) -> bool>;
+}
+
+impl<T> IsDefaultSliceExt<T> for [T]
+where
+    T: IsDefault,
+{
+    fn all_default(&self) -> bool {
+        T::is_default_slice(self)
+    }
+
+    fn count_non_default(&self) -> usize {
+        self.chunks(CHUNK)
+            .filter(|c| !T::is_default_slice(c))
+            .map(|c| c.iter().filter(|v| !v.is_default()).count())
+            .sum()
+    }
+
+    fn position_non_default(&self) -> Option<usize> {
+        self.chunks(CHUNK)
+            .enumerate()
+            .find(|(_, c)| !T::is_default_slice(c))
+            .and_then(|(i, c)| Some(i * CHUNK + c.iter().position(|v| !v.is_default())?))
+    }
+
+    fn trim_trailing_defaults(&self) -> &[T] {
+        let mut end = self.len();
+        for chunk in self.rchunks(CHUNK) {
+            if !T::is_default_slice(chunk) {
+                let last = chunk.iter().rposition(|v| !v.is_default()).unwrap_or(0);
+                return &self[..end - chunk.len() + last + 1];
+            }
+            end -= chunk.len();
+        }
+        &self[..0]
+    }
+
+    fn split_on_default(&self) -> Split<'_, T, fn(&T) -> bool> {
+        self.split(T::is_default)
+    }
+}
+
+/// `Vec` helpers built on [`IsDefault`].
+#[cfg(feature = "std")]
+pub trait IsDefaultVecExt {
+    /// Removes all default elements, keeping the order of the others.
+    fn retain_non_default(&mut self);
+
+    /// Removes the trailing default elements.
+    fn truncate_trailing_defaults(&mut self);
+}
+
+#[cfg(feature = "std")]
+impl<T> IsDefaultVecExt for Vec<T>
+where
+    T: IsDefault,
+{
+    fn retain_non_default(&mut self) {
+        self.retain(|v| !v.is_default());
+    }
+
+    fn truncate_trailing_defaults(&mut self) {
+        let len = self.trim_trailing_defaults().len();
+        self.truncate(len);
+    }
+}
@@ -0,0 +1,257 @@
+//! Hashing of non-default values only.
+//!
+//! [`DefaultInsensitiveHash`] feeds a value into a [`Hasher`] the way
+//! `Hash` does, except that derived impls skip default fields and tag every
+//! other field by its name, followed by the number of hashed fields.
+//! Adding a field whose value is default, or reordering fields, therefore
+//! leaves the hash unchanged.
+//!
+//! All impls write fixed-width little-endian bytes, so together with
+//! [`Fnv1a64`] the result of [`DefaultInsensitiveHash::fingerprint_u64`] is
+//! stable across platforms and compiler versions.
+
+use core::{hash::Hasher, time::Duration};
+
+/// Hashes a value, skipping default fields.
+pub trait DefaultInsensitiveHash {
+    /// Feeds `self` into `state`, skipping default fields.
+    fn hash_non_default<H: Hasher>(&self, state: &mut H);
+
+    /// Returns a stable 64-bit fingerprint of `self` computed with [`Fnv1a64`].
+    fn fingerprint_u64(&self) -> u64 {
+        let mut hasher = Fnv1a64::new();
+        self.hash_non_default(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// 64-bit FNV-1a hasher.
+///
+/// Unlike `DefaultHasher`, its output is fixed and does not depend on the
+/// platform or the Rust release.
+#[derive(Clone, Copy, Debug)]
+pub struct Fnv1a64(u64);
+
+impl Fnv1a64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    /// Creates a hasher with the FNV offset basis.
+    pub const fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+}
+
+impl Default for Fnv1a64 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher for Fnv1a64 {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+}
+
+/// Feeds a field name tag into `state`.
+#[doc(hidden)]
+pub fn write_tag<H: Hasher>(state: &mut H, name: &str) {
+    name.hash_non_default(state);
+}
+
+/// Feeds a length or field count into `state`.
+#[doc(hidden)]
+pub fn write_len<H: Hasher>(state: &mut H, len: usize) {
+    state.write(&(len as u64).to_le_bytes());
+}
+
+macro_rules! le_bytes_impl {
+    ($($ty:ty),*) => {
+        $(
+            impl DefaultInsensitiveHash for $ty {
+                fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+                    state.write(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+le_bytes_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl DefaultInsensitiveHash for usize {
+    fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+        (*self as u64).hash_non_default(state);
+    }
+}
+
+impl DefaultInsensitiveHash for isize {
+    fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+        (*self as i64).hash_non_default(state);
+    }
+}
+
+impl DefaultInsensitiveHash for bool {
+    fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+        (*self as u8).hash_non_default(state);
+    }
+}
+
+impl DefaultInsensitiveHash for char {
+    fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+        (*self as u32).hash_non_default(state);
+    }
+}
+
+impl DefaultInsensitiveHash for f32 {
+    fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+        self.to_bits().hash_non_default(state);
+    }
+}
+
+impl DefaultInsensitiveHash for f64 {
+    fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+        self.to_bits().hash_non_default(state);
+    }
+}
+
+impl DefaultInsensitiveHash for () {
+    fn hash_non_default<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl DefaultInsensitiveHash for str {
+    fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+        write_len(state, self.len());
+        state.write(self.as_bytes());
+    }
+}
+
+impl DefaultInsensitiveHash for Duration {
+    fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+        self.as_secs().hash_non_default(state);
+        self.subsec_nanos().hash_non_default(state);
+    }
+}
+
+impl<T> DefaultInsensitiveHash for Option<T>
+where
+    T: DefaultInsensitiveHash,
+{
+    fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+        match self {
+            None => 0u8.hash_non_default(state),
+            Some(v) => {
+                1u8.hash_non_default(state);
+                v.hash_non_default(state);
+            }
+        }
+    }
+}
+
+impl<T> DefaultInsensitiveHash for [T]
+where
+    T: DefaultInsensitiveHash,
+{
+    fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+        write_len(state, self.len());
+        self.iter().for_each(|v| v.hash_non_default(state));
+    }
+}
+
+impl<T, const N: usize> DefaultInsensitiveHash for [T; N]
+where
+    T: DefaultInsensitiveHash,
+{
+    fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash_non_default(state);
+    }
+}
+
+impl<T> DefaultInsensitiveHash for &T
+where
+    T: DefaultInsensitiveHash + ?Sized,
+{
+    fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+        (**self).hash_non_default(state);
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_types {
+    use super::{DefaultInsensitiveHash, write_len};
+    use std::{
+        collections::{BTreeMap, BTreeSet},
+        hash::Hasher,
+        path::{Path, PathBuf},
+    };
+
+    impl DefaultInsensitiveHash for String {
+        fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+            self.as_str().hash_non_default(state);
+        }
+    }
+
+    impl DefaultInsensitiveHash for Path {
+        fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+            let bytes = self.as_os_str().as_encoded_bytes();
+            write_len(state, bytes.len());
+            state.write(bytes);
+        }
+    }
+
+    impl DefaultInsensitiveHash for PathBuf {
+        fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+            self.as_path().hash_non_default(state);
+        }
+    }
+
+    impl<T> DefaultInsensitiveHash for Box<T>
+    where
+        T: DefaultInsensitiveHash + ?Sized,
+    {
+        fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+            (**self).hash_non_default(state);
+        }
+    }
+
+    impl<T> DefaultInsensitiveHash for Vec<T>
+    where
+        T: DefaultInsensitiveHash,
+    {
+        fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+            self.as_slice().hash_non_default(state);
+        }
+    }
+
+    impl<T> DefaultInsensitiveHash for BTreeSet<T>
+    where
+        T: DefaultInsensitiveHash,
+    {
+        fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+            write_len(state, self.len());
+            self.iter().for_each(|v| v.hash_non_default(state));
+        }
+    }
+
+    impl<K, V> DefaultInsensitiveHash for BTreeMap<K, V>
+    where
+        K: DefaultInsensitiveHash,
+        V: DefaultInsensitiveHash,
+    {
+        fn hash_non_default<H: Hasher>(&self, state: &mut H) {
+            write_len(state, self.len());
+            self.iter().for_each(|(k, v)| {
+                k.hash_non_default(state);
+                v.hash_non_default(state);
+            });
+        }
+    }
+}
@@ -0,0 +1,460 @@
+use core::{
+    cmp::Reverse,
+    marker::PhantomData,
+    num::{
+        NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize, Saturating, Wrapping,
+    },
+    time::Duration,
+};
+
+/// Checks whether a value matches a baseline instance.
+///
+/// This generalizes [`IsDefault`](crate::IsDefault) to defaults known only
+/// at runtime, such as a profile loaded at startup. Composite types compare
+/// their parts with their own `matches_baseline`, so nested structs,
+/// options and collections are checked field-wise.
+pub trait IsBaseline {
+    /// Returns `true` if `self` matches `baseline`.
+    fn matches_baseline(&self, baseline: &Self) -> bool;
+}
+
+macro_rules! eq_impl {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl IsBaseline for $ty {
+                #[inline]
+                fn matches_baseline(&self, baseline: &Self) -> bool {
+                    self == baseline
+                }
+            }
+        )*
+    };
+}
+
+eq_impl!(
+    bool,
+    char,
+    str,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+    Duration,
+);
+
+impl<T> IsBaseline for PhantomData<T>
+where
+    T: ?Sized,
+{
+    fn matches_baseline(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+macro_rules! wrapper_impl {
+    ($($ty:ident),*) => {
+        $(
+            impl<T> IsBaseline for $ty<T>
+            where
+                T: IsBaseline,
+            {
+                fn matches_baseline(&self, baseline: &Self) -> bool {
+                    self.0.matches_baseline(&baseline.0)
+                }
+            }
+        )*
+    };
+}
+
+wrapper_impl!(Reverse, Saturating, Wrapping);
+
+impl<T> IsBaseline for Option<T>
+where
+    T: IsBaseline,
+{
+    fn matches_baseline(&self, baseline: &Self) -> bool {
+        match (self, baseline) {
+            (None, None) => true,
+            (Some(v), Some(b)) => v.matches_baseline(b),
+            _ => false,
+        }
+    }
+}
+
+impl<T> IsBaseline for [T]
+where
+    T: IsBaseline,
+{
+    fn matches_baseline(&self, baseline: &Self) -> bool {
+        self.len() == baseline.len()
+            && self
+                .iter()
+                .zip(baseline)
+                .all(|(v, b)| v.matches_baseline(b))
+    }
+}
+
+impl<T, const N: usize> IsBaseline for [T; N]
+where
+    T: IsBaseline,
+{
+    fn matches_baseline(&self, baseline: &Self) -> bool {
+        self.as_slice().matches_baseline(baseline.as_slice())
+    }
+}
+
+impl<T> IsBaseline for &T
+where
+    T: IsBaseline + ?Sized,
+{
+    fn matches_baseline(&self, baseline: &Self) -> bool {
+        (**self).matches_baseline(*baseline)
+    }
+}
+
+macro_rules! tuple_impls {
+    () => {
+        eq_impl!(());
+    };
+
+    ($T:ident $t:ident $( $Ts:ident $ts:ident)*) => {
+        maybe_tuple_doc! {
+            $T $($Ts)* @
+            #[allow(non_snake_case)]
+            impl<$T: IsBaseline, $($Ts: IsBaseline),*> IsBaseline for ($T, $($Ts,)*) {
+                /// Returns `true` if all tuple fields match the baseline.
+                fn matches_baseline(&self, baseline: &Self) -> bool {
+                    let ($T, $($Ts,)*) = self;
+                    let ($t, $($ts,)*) = baseline;
+                    $T.matches_baseline($t) $( && $Ts.matches_baseline($ts) )*
+                }
+            }
+        }
+
+        tuple_impls!($($Ts $ts)*);
+    };
+}
+
+tuple_impls!(K k J j I i H h G g F f E e D d C c B b A a T t);
+
+#[cfg(feature = "std")]
+mod std_types {
+    use super::IsBaseline;
+    use std::{
+        borrow::Cow,
+        cell::{Cell, OnceCell, Ref, RefCell, RefMut},
+        collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
+        ffi::{CStr, CString, OsStr, OsString},
+        hash::{BuildHasher, Hash},
+        io::{Cursor, Empty, Sink},
+        marker::PhantomPinned,
+        path::{Path, PathBuf},
+        ptr,
+        rc::{self, Rc},
+        sync::{
+            self, Arc, Mutex, OnceLock, RwLock,
+            atomic::{
+                AtomicBool, AtomicI8, AtomicI16, AtomicI32, AtomicI64, AtomicIsize, AtomicU8,
+                AtomicU16, AtomicU32, AtomicU64, AtomicUsize, Ordering,
+            },
+        },
+    };
+
+    eq_impl!(String, CStr, CString, OsStr, OsString, Path, PathBuf,);
+
+    macro_rules! unit_impl {
+        ($($ty:ty),*) => {
+            $(
+                impl IsBaseline for $ty {
+                    fn matches_baseline(&self, _: &Self) -> bool {
+                        true
+                    }
+                }
+            )*
+        };
+    }
+
+    unit_impl!(Empty, PhantomPinned, Sink);
+
+    macro_rules! atomic_impl {
+        ($($ty:ty),*) => {
+            $(
+                impl IsBaseline for $ty {
+                    fn matches_baseline(&self, baseline: &Self) -> bool {
+                        self.load(Ordering::Relaxed) == baseline.load(Ordering::Relaxed)
+                    }
+                }
+            )*
+        };
+    }
+
+    atomic_impl!(
+        AtomicBool,
+        AtomicI8,
+        AtomicI16,
+        AtomicI32,
+        AtomicI64,
+        AtomicIsize,
+        AtomicU8,
+        AtomicU16,
+        AtomicU32,
+        AtomicU64,
+        AtomicUsize
+    );
+
+    macro_rules! deref_impl {
+        ($($ty:ident),*) => {
+            $(
+                impl<T> IsBaseline for $ty<T>
+                where
+                    T: IsBaseline + ?Sized,
+                {
+                    fn matches_baseline(&self, baseline: &Self) -> bool {
+                        (**self).matches_baseline(&**baseline)
+                    }
+                }
+            )*
+        };
+    }
+
+    deref_impl!(Box, Rc, Arc);
+
+    impl<T> IsBaseline for Ref<'_, T>
+    where
+        T: IsBaseline + ?Sized,
+    {
+        fn matches_baseline(&self, baseline: &Self) -> bool {
+            (**self).matches_baseline(&**baseline)
+        }
+    }
+
+    impl<T> IsBaseline for RefMut<'_, T>
+    where
+        T: IsBaseline + ?Sized,
+    {
+        fn matches_baseline(&self, baseline: &Self) -> bool {
+            (**self).matches_baseline(&**baseline)
+        }
+    }
+
+    macro_rules! lock_impl {
+        ($ty:ident, $lock:ident) => {
+            impl<T> IsBaseline for $ty<T>
+            where
+                T: IsBaseline + ?Sized,
+            {
+                /// Returns `true` if the inner values match.
+                #[doc = concat!("Always returns `false` if `", stringify!($lock), "()` returns an error, unless `self` is `baseline`.")]
+                fn matches_baseline(&self, baseline: &Self) -> bool {
+                    ptr::eq(self, baseline)
+                        || match (self.$lock(), baseline.$lock()) {
+                            (Ok(v), Ok(b)) => (*v).matches_baseline(&*b),
+                            _ => false,
+                        }
+                }
+            }
+        };
+    }
+
+    lock_impl!(RefCell, try_borrow);
+    lock_impl!(RwLock, try_read);
+    lock_impl!(Mutex, try_lock);
+
+    impl<T> IsBaseline for Cell<T>
+    where
+        T: Copy + IsBaseline,
+    {
+        fn matches_baseline(&self, baseline: &Self) -> bool {
+            self.get().matches_baseline(&baseline.get())
+        }
+    }
+
+    macro_rules! once_impl {
+        ($($ty:ident),*) => {
+            $(
+                impl<T> IsBaseline for $ty<T>
+                where
+                    T: IsBaseline,
+                {
+                    fn matches_baseline(&self, baseline: &Self) -> bool {
+                        self.get().matches_baseline(&baseline.get())
+                    }
+                }
+            )*
+        };
+    }
+
+    once_impl!(OnceCell, OnceLock);
+
+    macro_rules! weak_impl {
+        ($($ty:ty),*) => {
+            $(
+                impl<T> IsBaseline for $ty
+                where
+                    T: IsBaseline + ?Sized,
+                {
+                    /// Returns `true` if both upgrade to matching values, or
+                    /// neither upgrades.
+                    fn matches_baseline(&self, baseline: &Self) -> bool {
+                        self.upgrade().matches_baseline(&baseline.upgrade())
+                    }
+                }
+            )*
+        };
+    }
+
+    weak_impl!(rc::Weak<T>, sync::Weak<T>);
+
+    impl<T> IsBaseline for Cursor<T>
+    where
+        T: IsBaseline,
+    {
+        fn matches_baseline(&self, baseline: &Self) -> bool {
+            self.position() == baseline.position()
+                && self.get_ref().matches_baseline(baseline.get_ref())
+        }
+    }
+
+    impl<T> IsBaseline for Cow<'_, T>
+    where
+        T: IsBaseline + ToOwned + ?Sized,
+    {
+        fn matches_baseline(&self, baseline: &Self) -> bool {
+            (**self).matches_baseline(&**baseline)
+        }
+    }
+
+    impl<T> IsBaseline for Vec<T>
+    where
+        T: IsBaseline,
+    {
+        fn matches_baseline(&self, baseline: &Self) -> bool {
+            self.as_slice().matches_baseline(baseline.as_slice())
+        }
+    }
+
+    impl<T> IsBaseline for VecDeque<T>
+    where
+        T: IsBaseline,
+    {
+        fn matches_baseline(&self, baseline: &Self) -> bool {
+            self.len() == baseline.len()
+                && self
+                    .iter()
+                    .zip(baseline)
+                    .all(|(v, b)| v.matches_baseline(b))
+        }
+    }
+
+    impl<T> IsBaseline for LinkedList<T>
+    where
+        T: IsBaseline,
+    {
+        fn matches_baseline(&self, baseline: &Self) -> bool {
+            self.len() == baseline.len()
+                && self
+                    .iter()
+                    .zip(baseline)
+                    .all(|(v, b)| v.matches_baseline(b))
+        }
+    }
+
+    impl<T> IsBaseline for BinaryHeap<T>
+    where
+        T: Ord + IsBaseline,
+    {
+        /// Returns `true` if both heaps hold matching elements, regardless
+        /// of their internal order.
+        fn matches_baseline(&self, baseline: &Self) -> bool {
+            if self.len() != baseline.len() {
+                return false;
+            }
+            let mut values: Vec<_> = self.iter().collect();
+            let mut baseline: Vec<_> = baseline.iter().collect();
+            values.sort_unstable();
+            baseline.sort_unstable();
+            values.matches_baseline(&baseline)
+        }
+    }
+
+    impl<T> IsBaseline for BTreeSet<T>
+    where
+        T: Ord,
+    {
+        fn matches_baseline(&self, baseline: &Self) -> bool {
+            self == baseline
+        }
+    }
+
+    impl<T, S> IsBaseline for HashSet<T, S>
+    where
+        T: Eq + Hash,
+        S: BuildHasher,
+    {
+        fn matches_baseline(&self, baseline: &Self) -> bool {
+            self == baseline
+        }
+    }
+
+    impl<K, V> IsBaseline for BTreeMap<K, V>
+    where
+        K: Ord,
+        V: IsBaseline,
+    {
+        fn matches_baseline(&self, baseline: &Self) -> bool {
+            self.len() == baseline.len()
+                && self
+                    .iter()
+                    .all(|(k, v)| baseline.get(k).is_some_and(|b| v.matches_baseline(b)))
+        }
+    }
+
+    impl<K, V, S> IsBaseline for HashMap<K, V, S>
+    where
+        K: Eq + Hash,
+        V: IsBaseline,
+        S: BuildHasher,
+    {
+        fn matches_baseline(&self, baseline: &Self) -> bool {
+            self.len() == baseline.len()
+                && self
+                    .iter()
+                    .all(|(k, v)| baseline.get(k).is_some_and(|b| v.matches_baseline(b)))
+        }
+    }
+
+    #[cfg(feature = "bstr")]
+    eq_impl!(std::bstr::ByteStr, std::bstr::ByteString);
+}
+
+#[cfg(feature = "f16")]
+eq_impl!(f16);
+
+#[cfg(feature = "f128")]
+eq_impl!(f128);
+
+#[cfg(feature = "ascii_char")]
+eq_impl!(core::ascii::Char);
@@ -0,0 +1,423 @@
+use core::{
+    marker::PhantomData,
+    num::{Saturating, Wrapping},
+    time::Duration,
+};
+
+/// Checks whether a value is blank, a looser notion than default.
+///
+/// Unlike [`IsDefault`](crate::IsDefault), which holds only for
+/// `self == Default::default()`, blank values also include:
+/// - `Some(blank)`
+/// - strings and characters made of whitespace
+/// - collections whose elements, or map values, are all blank
+///
+/// This suits form validation and "empty" indicators. Derived impls can
+/// fall back to `IsDefault` with `#[is_blank(strict)]`, and choose which
+/// whitespace strings may hold with `#[is_blank(trim = "...")]`.
+pub trait IsBlank {
+    /// Returns `true` if `self` is blank.
+    fn is_blank(&self) -> bool;
+
+    /// Returns `true` if `self` is blank, with strings trimmed as `trim`.
+    ///
+    /// [`is_blank`](IsBlank::is_blank) trims Unicode whitespace. Strings,
+    /// characters and the containers of this crate override this, and it
+    /// falls back to `is_blank` for other types.
+    #[inline]
+    fn is_blank_with(&self, trim: Trim) -> bool {
+        let _ = trim;
+        self.is_blank()
+    }
+}
+
+/// Which whitespace a string may hold and still be blank.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Trim {
+    /// Unicode whitespace, as in `str::trim`.
+    #[default]
+    Unicode,
+    /// ASCII whitespace, as in `str::trim_ascii`.
+    Ascii,
+    /// No whitespace, so only empty strings are blank.
+    Never,
+}
+
+macro_rules! zero_impl {
+    ($($t:ty),*) => {
+        $(
+            impl IsBlank for $t {
+                /// Returns `true` if self is zero.
+                #[inline]
+                fn is_blank(&self) -> bool {
+                    *self == 0 as $t
+                }
+            }
+        )*
+    };
+}
+
+zero_impl!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+impl IsBlank for bool {
+    /// Returns `true` if self is `false`.
+    #[inline]
+    fn is_blank(&self) -> bool {
+        !*self
+    }
+}
+
+impl IsBlank for char {
+    /// Returns `true` if self is `'\x00'` or whitespace.
+    #[inline]
+    fn is_blank(&self) -> bool {
+        self.is_blank_with(Trim::Unicode)
+    }
+
+    #[inline]
+    fn is_blank_with(&self, trim: Trim) -> bool {
+        *self == '\x00'
+            || match trim {
+                Trim::Unicode => self.is_whitespace(),
+                Trim::Ascii => self.is_ascii_whitespace(),
+                Trim::Never => false,
+            }
+    }
+}
+
+impl IsBlank for str {
+    /// Returns `true` if self is empty or whitespace-only.
+    #[inline]
+    fn is_blank(&self) -> bool {
+        self.is_blank_with(Trim::Unicode)
+    }
+
+    #[inline]
+    fn is_blank_with(&self, trim: Trim) -> bool {
+        match trim {
+            Trim::Unicode => self.trim().is_empty(),
+            Trim::Ascii => self.trim_ascii().is_empty(),
+            Trim::Never => self.is_empty(),
+        }
+    }
+}
+
+impl<T> IsBlank for PhantomData<T>
+where
+    T: ?Sized,
+{
+    /// Always returns `true`.
+    #[inline(always)]
+    fn is_blank(&self) -> bool {
+        true
+    }
+}
+
+impl IsBlank for Duration {
+    /// Returns `true` if self is zero.
+    #[inline]
+    fn is_blank(&self) -> bool {
+        self.is_zero()
+    }
+}
+
+macro_rules! wrapper_impl {
+    ($($ty:ident),*) => {
+        $(
+            impl<T> IsBlank for $ty<T>
+            where
+                T: IsBlank,
+            {
+                #[inline]
+                fn is_blank(&self) -> bool {
+                    self.0.is_blank()
+                }
+            }
+        )*
+    };
+}
+
+wrapper_impl!(Saturating, Wrapping);
+
+impl<T> IsBlank for Option<T>
+where
+    T: IsBlank,
+{
+    /// Returns `true` if self is `None` or `Some(blank)`.
+    #[inline]
+    fn is_blank(&self) -> bool {
+        self.as_ref().is_none_or(T::is_blank)
+    }
+
+    #[inline]
+    fn is_blank_with(&self, trim: Trim) -> bool {
+        self.as_ref().is_none_or(|v| v.is_blank_with(trim))
+    }
+}
+
+impl<T> IsBlank for [T]
+where
+    T: IsBlank,
+{
+    /// Returns `true` if all elements are blank.
+    #[inline]
+    fn is_blank(&self) -> bool {
+        self.iter().all(T::is_blank)
+    }
+
+    #[inline]
+    fn is_blank_with(&self, trim: Trim) -> bool {
+        self.iter().all(|v| v.is_blank_with(trim))
+    }
+}
+
+impl<T, const N: usize> IsBlank for [T; N]
+where
+    T: IsBlank,
+{
+    /// Returns `true` if all elements are blank.
+    #[inline]
+    fn is_blank(&self) -> bool {
+        self.as_slice().is_blank()
+    }
+
+    #[inline]
+    fn is_blank_with(&self, trim: Trim) -> bool {
+        self.as_slice().is_blank_with(trim)
+    }
+}
+
+macro_rules! ref_impl {
+    ($($t:ty),*) => {
+        $(
+            impl<T> IsBlank for $t
+            where
+                T: IsBlank + ?Sized,
+            {
+                #[inline]
+                fn is_blank(&self) -> bool {
+                    (**self).is_blank()
+                }
+
+                #[inline]
+                fn is_blank_with(&self, trim: Trim) -> bool {
+                    (**self).is_blank_with(trim)
+                }
+            }
+        )*
+    };
+}
+
+ref_impl!(&T, &mut T);
+
+macro_rules! tuple_impls {
+    () => {
+        impl IsBlank for () {
+            /// Always returns `true`.
+            #[inline(always)]
+            fn is_blank(&self) -> bool {
+                true
+            }
+        }
+    };
+
+    ($T:ident $( $Ts:ident)*) => {
+        maybe_tuple_doc! {
+            $T $($Ts)* @
+            #[allow(non_snake_case)]
+            impl<$T: IsBlank, $($Ts: IsBlank),*> IsBlank for ($T, $($Ts,)*) {
+                /// Returns `true` if all tuple fields are blank.
+                #[inline]
+                fn is_blank(&self) -> bool {
+                    let ($T, $($Ts,)*) = self;
+                    $T.is_blank() $( && $Ts.is_blank() )*
+                }
+
+                #[inline]
+                fn is_blank_with(&self, trim: Trim) -> bool {
+                    let ($T, $($Ts,)*) = self;
+                    $T.is_blank_with(trim) $( && $Ts.is_blank_with(trim) )*
+                }
+            }
+        }
+
+        tuple_impls!($($Ts)*);
+    };
+}
+
+tuple_impls!(K J I H G F E D C B A T);
+
+#[cfg(feature = "std")]
+mod std_types {
+    use super::{IsBlank, Trim};
+    use std::{
+        borrow::Cow,
+        collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
+        ffi::{OsStr, OsString},
+        path::{Path, PathBuf},
+        rc::Rc,
+        sync::Arc,
+    };
+
+    ref_impl!(Box<T>, Rc<T>, Arc<T>);
+
+    impl IsBlank for String {
+        /// Returns `true` if self is empty or whitespace-only.
+        #[inline]
+        fn is_blank(&self) -> bool {
+            self.as_str().is_blank()
+        }
+
+        #[inline]
+        fn is_blank_with(&self, trim: Trim) -> bool {
+            self.as_str().is_blank_with(trim)
+        }
+    }
+
+    impl IsBlank for OsStr {
+        /// Returns `true` if self is empty or ASCII whitespace-only.
+        #[inline]
+        fn is_blank(&self) -> bool {
+            self.is_blank_with(Trim::Unicode)
+        }
+
+        /// Trims ASCII whitespace for [`Trim::Unicode`], since `OsStr` is
+        /// not necessarily Unicode.
+        #[inline]
+        fn is_blank_with(&self, trim: Trim) -> bool {
+            let bytes = self.as_encoded_bytes();
+            match trim {
+                Trim::Unicode | Trim::Ascii => bytes.trim_ascii().is_empty(),
+                Trim::Never => bytes.is_empty(),
+            }
+        }
+    }
+
+    impl IsBlank for OsString {
+        /// Returns `true` if self is empty or whitespace-only.
+        #[inline]
+        fn is_blank(&self) -> bool {
+            self.as_os_str().is_blank()
+        }
+
+        #[inline]
+        fn is_blank_with(&self, trim: Trim) -> bool {
+            self.as_os_str().is_blank_with(trim)
+        }
+    }
+
+    impl IsBlank for Path {
+        /// Returns `true` if self is empty or whitespace-only.
+        #[inline]
+        fn is_blank(&self) -> bool {
+            self.as_os_str().is_blank()
+        }
+
+        #[inline]
+        fn is_blank_with(&self, trim: Trim) -> bool {
+            self.as_os_str().is_blank_with(trim)
+        }
+    }
+
+    impl IsBlank for PathBuf {
+        /// Returns `true` if self is empty or whitespace-only.
+        #[inline]
+        fn is_blank(&self) -> bool {
+            self.as_os_str().is_blank()
+        }
+
+        #[inline]
+        fn is_blank_with(&self, trim: Trim) -> bool {
+            self.as_os_str().is_blank_with(trim)
+        }
+    }
+
+    impl<T> IsBlank for Cow<'_, T>
+    where
+        T: IsBlank + ToOwned + ?Sized,
+    {
+        #[inline]
+        fn is_blank(&self) -> bool {
+            (**self).is_blank()
+        }
+
+        #[inline]
+        fn is_blank_with(&self, trim: Trim) -> bool {
+            (**self).is_blank_with(trim)
+        }
+    }
+
+    macro_rules! elements_impl {
+        ($($ty:ident),*) => {
+            $(
+                impl<T> IsBlank for $ty<T>
+                where
+                    T: IsBlank,
+                {
+                    /// Returns `true` if all elements are blank.
+                    #[inline]
+                    fn is_blank(&self) -> bool {
+                        self.iter().all(T::is_blank)
+                    }
+
+                    #[inline]
+                    fn is_blank_with(&self, trim: Trim) -> bool {
+                        self.iter().all(|v| v.is_blank_with(trim))
+                    }
+                }
+            )*
+        };
+    }
+
+    elements_impl!(Vec, VecDeque, LinkedList, BinaryHeap, BTreeSet);
+
+    impl<T, S> IsBlank for HashSet<T, S>
+    where
+        T: IsBlank,
+    {
+        /// Returns `true` if all elements are blank.
+        #[inline]
+        fn is_blank(&self) -> bool {
+            self.iter().all(T::is_blank)
+        }
+
+        #[inline]
+        fn is_blank_with(&self, trim: Trim) -> bool {
+            self.iter().all(|v| v.is_blank_with(trim))
+        }
+    }
+
+    impl<K, V> IsBlank for BTreeMap<K, V>
+    where
+        V: IsBlank,
+    {
+        /// Returns `true` if all values are blank.
+        #[inline]
+        fn is_blank(&self) -> bool {
+            self.values().all(V::is_blank)
+        }
+
+        #[inline]
+        fn is_blank_with(&self, trim: Trim) -> bool {
+            self.values().all(|v| v.is_blank_with(trim))
+        }
+    }
+
+    impl<K, V, S> IsBlank for HashMap<K, V, S>
+    where
+        V: IsBlank,
+    {
+        /// Returns `true` if all values are blank.
+        #[inline]
+        fn is_blank(&self) -> bool {
+            self.values().all(V::is_blank)
+        }
+
+        #[inline]
+        fn is_blank_with(&self, trim: Trim) -> bool {
+            self.values().all(|v| v.is_blank_with(trim))
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::IsDefault;
+
+/// Checks whether parts of a struct are default.
+///
+/// Derived impls dispatch on field names with `match`, so no reflection is
+/// involved. Fields join groups with `#[is_default(group = "...")]`, and
+/// paths go into a field of a type that implements this trait when it is
+/// marked `#[is_default(nested)]`.
+pub trait IsDefaultFields: IsDefault {
+    /// Returns whether the field at the dot-separated `path` is default,
+    /// or `None` if there is no such field.
+    fn is_default_at(&self, path: &str) -> Option<bool>;
+
+    /// Returns `true` if all fields in `group` are default.
+    ///
+    /// Returns `true` for unknown groups.
+    fn is_group_default(&self, group: &str) -> bool;
+
+    /// Returns `true` if all fields except those named in `fields` are default.
+    fn is_default_except(&self, fields: &[&str]) -> bool;
+}
@@ -4,11 +4,19 @@
 #![cfg_attr(feature = "bstr", feature(bstr))]
 #![cfg_attr(feature = "f16", feature(f16))]
 #![cfg_attr(feature = "f128", feature(f128))]
+#![cfg_attr(feature = "portable_simd", feature(portable_simd))]
 
 #[cfg(feature = "derive")]
 extern crate is_default_derive;
 #[cfg(feature = "derive")]
-pub use is_default_derive::IsDefault;
+pub use is_default_derive::{
+    ConstDefault, DefaultInsensitiveHash, IsBaseline, IsBlank, IsDefault, IsDefaultFields,
+    IsZeroBytes, NonDefaultDebug, NormalizeDefaults, ResetDefault, skip_defaults,
+};
+#[cfg(all(feature = "derive", feature = "sparse"))]
+pub use is_default_derive::{SparseDecode, SparseEncode};
+#[cfg(all(feature = "derive", feature = "args"))]
+pub use is_default_derive::{ToArgs, ToEnv};
 
 /// Checks whether a value is equal to its type's default.
 pub trait IsDefault {
@@ -16,11 +24,136 @@ pub trait IsDefault {
     ///
     /// Implementations must ensure that the condition `self == &Self::default()` holds.
     fn is_default(&self) -> bool;
+
+    /// Returns `true` if all elements of `slice` are default.
+    ///
+    /// `[T]` and `[T; N]` delegate to this, so types with a cheaper bulk
+    /// check can override it, like `Hash::hash_slice`. Primitive integers
+    /// and floats use chunked OR-reduction, or `core::simd` with the
+    /// `portable_simd` feature.
+    #[inline]
+    fn is_default_slice(slice: &[Self]) -> bool
+    where
+        Self: Sized,
+    {
+        slice.iter().all(Self::is_default)
+    }
+}
+
+/// Returns `true` if `value` is equal to the default value for its type.
+///
+/// This is a free function form of [`IsDefault::is_default`], intended for
+/// `#[serde(skip_serializing_if = "is_default::is_default")]`. It does not
+/// need the trait in scope.
+#[inline]
+pub fn is_default<T>(value: &T) -> bool
+where
+    T: IsDefault + ?Sized,
+{
+    value.is_default()
+}
+
+// Used by the tuple impls. If this is a unary tuple, it adds a doc comment.
+// Otherwise, it hides the docs entirely.
+macro_rules! maybe_tuple_doc {
+    ($a:ident @ #[$meta:meta] $item:item) => {
+        #[doc = "This trait is implemented for tuples up to twelve items long."]
+        #[$meta]
+        $item
+    };
+    ($a:ident $($rest_a:ident)+ @ #[$meta:meta] $item:item) => {
+        #[doc(hidden)]
+        #[$meta]
+        $item
+    };
 }
 
 #[cfg(not(feature = "via_default_eq"))]
 mod not_via_default_eq;
 
+mod reset_default;
+pub use reset_default::ResetDefault;
+
+mod is_default_fields;
+pub use is_default_fields::IsDefaultFields;
+
+mod is_baseline;
+pub use is_baseline::IsBaseline;
+
+mod const_default;
+pub use const_default::ConstDefault;
+#[doc(hidden)]
+pub use const_default::Probe;
+
+mod is_blank;
+pub use is_blank::{IsBlank, Trim};
+
+mod default_as_none;
+pub use default_as_none::DefaultAsNone;
+
+mod non_default;
+pub use non_default::NonDefault;
+
+mod presence;
+pub use presence::Presence;
+
+#[cfg(feature = "std")]
+mod default_box;
+#[cfg(feature = "std")]
+pub use default_box::DefaultBox;
+
+pub mod with_default;
+pub use with_default::{
+    WithDefault, WithDefaultBool, WithDefaultChar, WithDefaultI8, WithDefaultI16, WithDefaultI32,
+    WithDefaultI64, WithDefaultI128, WithDefaultIsize, WithDefaultU8, WithDefaultU16,
+    WithDefaultU32, WithDefaultU64, WithDefaultU128, WithDefaultUsize,
+};
+
+pub mod normalize;
+pub use normalize::NormalizeDefaults;
+
+mod ext;
+#[cfg(feature = "std")]
+pub use ext::IsDefaultVecExt;
+pub use ext::{IsDefaultExt, IsDefaultIterExt, IsDefaultSliceExt};
+
+pub mod zero_bytes;
+pub use zero_bytes::IsZeroBytes;
+
+pub mod version;
+pub use version::Version;
+
+pub mod fingerprint;
+pub use fingerprint::DefaultInsensitiveHash;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+mod merge_patch;
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+pub use merge_patch::{apply_merge_patch, mask_baseline, to_merge_patch, to_merge_patch_from};
+
+#[cfg(feature = "args")]
+pub mod args;
+#[cfg(feature = "args")]
+pub use args::{ToArgs, ToEnv};
+
+#[cfg(feature = "rayon")]
+mod par_is_default;
+#[cfg(feature = "rayon")]
+pub use par_is_default::ParIsDefault;
+
+#[cfg(feature = "sparse")]
+pub mod sparse;
+#[cfg(feature = "sparse")]
+pub use sparse::{SparseDecode, SparseEncode};
+
+#[cfg(any(feature = "serde_json", feature = "serde_yaml", feature = "toml"))]
+mod prune_defaults;
+#[cfg(any(feature = "serde_json", feature = "serde_yaml", feature = "toml"))]
+pub use prune_defaults::PruneDefaults;
+
 #[cfg(feature = "via_default_eq")]
 mod via_default_eq {
     use crate::IsDefault;
@@ -0,0 +1,181 @@
+use crate::{IsBaseline, IsDefault};
+use serde::{Serialize, de::DeserializeOwned, ser::Error as _};
+use serde_json::{Error, Map, Value};
+
+/// Returns a JSON Merge Patch ([RFC 7396]) that turns the default value of
+/// `T` into `value`.
+///
+/// A default `value` produces `{}`, checked with [`IsDefault`]. Otherwise,
+/// `value` and `T::default()` are serialized and compared member by
+/// member, recursing into nested objects, and the patch holds only the
+/// members that differ.
+///
+/// In a merge patch, `null` removes a member, so a member cannot be set to
+/// `null` if it is not `null` in the default. This happens with
+/// `#[serde(default)]` containers whose `Default` sets an `Option` field to
+/// `Some`, while `value` has `None`, and with maps holding `None` values
+/// under keys the default does not have. Applying such a patch would
+/// silently drop the member, so this returns an error instead.
+///
+/// Returns an error if `T` or its default fails to serialize to JSON, or
+/// if a member changes to or is added as `null`.
+///
+/// [RFC 7396]: https://www.rfc-editor.org/rfc/rfc7396
+pub fn to_merge_patch<T>(value: &T) -> Result<Value, Error>
+where
+    T: Serialize + Default + IsDefault,
+{
+    if value.is_default() {
+        return Ok(Value::Object(Map::new()));
+    }
+
+    let source = serde_json::to_value(T::default())?;
+    let target = serde_json::to_value(value)?;
+    diff(source, target, Nulls::Reject)
+}
+
+/// Returns a JSON Merge Patch ([RFC 7396]) that turns `baseline` into
+/// `value`.
+///
+/// This is [`to_merge_patch`] with a runtime baseline in place of
+/// `T::default()`. A `value` that matches the baseline, checked with
+/// [`IsBaseline`], produces `{}`. Members that `value` lacks are removed
+/// with `null`, and the same errors apply for members changing to `null`.
+///
+/// [RFC 7396]: https://www.rfc-editor.org/rfc/rfc7396
+pub fn to_merge_patch_from<T>(baseline: &T, value: &T) -> Result<Value, Error>
+where
+    T: Serialize + IsBaseline,
+{
+    if value.matches_baseline(baseline) {
+        return Ok(Value::Object(Map::new()));
+    }
+
+    let source = serde_json::to_value(baseline)?;
+    let target = serde_json::to_value(value)?;
+    diff(source, target, Nulls::Reject)
+}
+
+/// Returns `value` as JSON with the members that match `baseline` masked
+/// out, for logging what a profile overrides.
+///
+/// Nested objects keep only their differing members, and members that
+/// `value` lacks show as `null`. Unlike a merge patch, `null` values are
+/// kept as they are, so this only fails if serialization fails.
+pub fn mask_baseline<T>(baseline: &T, value: &T) -> Result<Value, Error>
+where
+    T: Serialize + IsBaseline,
+{
+    if value.matches_baseline(baseline) {
+        return Ok(Value::Object(Map::new()));
+    }
+
+    let source = serde_json::to_value(baseline)?;
+    let target = serde_json::to_value(value)?;
+    diff(source, target, Nulls::Keep)
+}
+
+/// Applies a JSON Merge Patch ([RFC 7396]) to `target`.
+///
+/// Returns an error if `target` fails to serialize to JSON, or if the
+/// patched document fails to deserialize back. On error, `target` is
+/// left unchanged.
+///
+/// [RFC 7396]: https://www.rfc-editor.org/rfc/rfc7396
+pub fn apply_merge_patch<T>(target: &mut T, patch: Value) -> Result<(), Error>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let mut doc = serde_json::to_value(&*target)?;
+    merge(&mut doc, patch);
+    *target = serde_json::from_value(doc)?;
+    Ok(())
+}
+
+/// How [`diff`] handles `null` values that a merge patch cannot express.
+#[derive(Clone, Copy, PartialEq)]
+enum Nulls {
+    Reject,
+    Keep,
+}
+
+fn diff(source: Value, target: Value, nulls: Nulls) -> Result<Value, Error> {
+    let (mut source, target) = match (source, target) {
+        (Value::Object(source), Value::Object(target)) => (source, target),
+        (_, target) => {
+            if nulls == Nulls::Reject {
+                reject_null_members(&target)?;
+            }
+            return Ok(target);
+        }
+    };
+
+    let mut patch = Map::new();
+
+    for (k, t) in target {
+        match source.remove(&k) {
+            Some(s) if s == t => {}
+            Some(_) if t.is_null() && nulls == Nulls::Reject => {
+                return Err(null_member(&k));
+            }
+            Some(s) => {
+                patch.insert(k, diff(s, t, nulls)?);
+            }
+            None => {
+                if nulls == Nulls::Reject {
+                    reject_nulls(&k, &t)?;
+                }
+                patch.insert(k, t);
+            }
+        }
+    }
+
+    for k in source.into_iter().map(|(k, _)| k) {
+        patch.insert(k, Value::Null);
+    }
+
+    Ok(Value::Object(patch))
+}
+
+/// Checks a value that the patch sets as a whole, where any `null` member
+/// would be removed when applied.
+fn reject_nulls(key: &str, value: &Value) -> Result<(), Error> {
+    if value.is_null() {
+        return Err(null_member(key));
+    }
+    reject_null_members(value)
+}
+
+fn reject_null_members(value: &Value) -> Result<(), Error> {
+    match value {
+        Value::Object(map) => map.iter().try_for_each(|(k, v)| reject_nulls(k, v)),
+        _ => Ok(()),
+    }
+}
+
+fn null_member(key: &str) -> Error {
+    Error::custom(format_args!(
+        "member `{key}` changes to null, which a merge patch cannot express"
+    ))
+}
+
+fn merge(target: &mut Value, patch: Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch;
+        return;
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+
+    if let Value::Object(map) = target {
+        for (k, v) in patch {
+            if v.is_null() {
+                map.remove(&k);
+            } else {
+                merge(map.entry(k).or_insert(Value::Null), v);
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{DefaultAsNone, IsDefault};
+use core::{borrow::Borrow, fmt, ops::Deref};
+
+/// A value that is guaranteed not to be default.
+///
+/// Like `NonZeroU32` for integers, this validates on construction, so that
+/// a non-empty `String` or a non-zero `Duration` is encoded in the type.
+/// There is no mutable access to the value, which keeps the guarantee.
+///
+/// With the `serde` feature, it serializes as `T` and fails to deserialize
+/// default values.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonDefault<T>(T);
+
+impl<T> NonDefault<T>
+where
+    T: IsDefault,
+{
+    /// Returns `Some` if `value` is not default, otherwise `None`.
+    #[inline]
+    pub fn new(value: T) -> Option<Self> {
+        if value.is_default() {
+            None
+        } else {
+            Some(Self(value))
+        }
+    }
+
+    /// Wraps `value` without checking it.
+    ///
+    /// The guarantee is a logical one, not a memory-safety one, so this is
+    /// safe. Passing a default value is a bug, caught by a debug assertion.
+    #[inline]
+    pub fn new_unchecked(value: T) -> Self {
+        debug_assert!(
+            !value.is_default(),
+            "NonDefault::new_unchecked with a default value"
+        );
+        Self(value)
+    }
+}
+
+impl<T> NonDefault<T> {
+    /// Returns a reference to the value.
+    #[inline]
+    pub const fn get(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns the value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for NonDefault<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for NonDefault<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> Borrow<T> for NonDefault<T> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> fmt::Debug for NonDefault<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T> fmt::Display for NonDefault<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T> IsDefault for NonDefault<T> {
+    /// Always returns `false`.
+    #[inline(always)]
+    fn is_default(&self) -> bool {
+        false
+    }
+}
+
+impl<T> From<Option<NonDefault<T>>> for DefaultAsNone<T>
+where
+    T: IsDefault + Default,
+{
+    /// Converts `None` to `T::default()`.
+    #[inline]
+    fn from(value: Option<NonDefault<T>>) -> Self {
+        value.map(NonDefault::into_inner).into()
+    }
+}
+
+impl<T> From<DefaultAsNone<T>> for Option<NonDefault<T>>
+where
+    T: IsDefault + Default,
+{
+    #[inline]
+    fn from(value: DefaultAsNone<T>) -> Self {
+        value.into_option().map(NonDefault)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod feature_serde {
+    use super::NonDefault;
+    use crate::IsDefault;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+    impl<T> Serialize for NonDefault<T>
+    where
+        T: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.0.serialize(serializer)
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for NonDefault<T>
+    where
+        T: IsDefault + Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            NonDefault::new(T::deserialize(deserializer)?)
+                .ok_or_else(|| D::Error::custom("expected a non-default value"))
+        }
+    }
+}
@@ -0,0 +1,234 @@
+//! Normalizing default values inside containers.
+//!
+//! [`NormalizeDefaults`] rewrites values that are equivalent to the default
+//! into one canonical form, so that equal configs compare equal and
+//! serialize the same. Maps always drop entries whose value is default.
+//! Collapsing `Some(default)` into `None` and trimming trailing defaults from
+//! `Vec` change the meaning of some data, so the derive only does them for
+//! fields marked `#[normalize(collapse)]` and `#[normalize(trim)]`.
+
+use crate::IsDefault;
+use core::{
+    marker::PhantomData,
+    num::{Saturating, Wrapping},
+    time::Duration,
+};
+
+/// Recursively normalizes default values.
+pub trait NormalizeDefaults {
+    /// Normalizes `self` in place.
+    fn normalize_defaults(&mut self);
+
+    /// Returns `true` if `self` and `other` are equal after normalization.
+    ///
+    /// Neither value is modified.
+    fn eq_normalized(&self, other: &Self) -> bool
+    where
+        Self: Clone + PartialEq,
+    {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        a.normalize_defaults();
+        b.normalize_defaults();
+        a == b
+    }
+}
+
+/// Replaces `Some(default)` with `None`, after normalizing the value.
+pub fn collapse_option<T>(value: &mut Option<T>)
+where
+    T: NormalizeDefaults + IsDefault,
+{
+    value.normalize_defaults();
+    if value.as_ref().is_some_and(T::is_default) {
+        *value = None;
+    }
+}
+
+/// Removes trailing default elements, after normalizing all elements.
+#[cfg(feature = "std")]
+pub fn trim_trailing<T>(value: &mut Vec<T>)
+where
+    T: NormalizeDefaults + IsDefault,
+{
+    value.normalize_defaults();
+    crate::IsDefaultVecExt::truncate_trailing_defaults(value);
+}
+
+macro_rules! noop_impl {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl NormalizeDefaults for $t {
+                #[inline(always)]
+                fn normalize_defaults(&mut self) {}
+            }
+        )*
+    };
+}
+
+noop_impl!(
+    (),
+    bool,
+    char,
+    f32,
+    f64,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    Duration,
+    &str,
+);
+
+impl<T> NormalizeDefaults for PhantomData<T>
+where
+    T: ?Sized,
+{
+    #[inline(always)]
+    fn normalize_defaults(&mut self) {}
+}
+
+macro_rules! wrapper_impl {
+    ($($ty:ident),*) => {
+        $(
+            impl<T> NormalizeDefaults for $ty<T>
+            where
+                T: NormalizeDefaults,
+            {
+                fn normalize_defaults(&mut self) {
+                    self.0.normalize_defaults();
+                }
+            }
+        )*
+    };
+}
+
+wrapper_impl!(Saturating, Wrapping);
+
+impl<T> NormalizeDefaults for Option<T>
+where
+    T: NormalizeDefaults,
+{
+    fn normalize_defaults(&mut self) {
+        if let Some(v) = self {
+            v.normalize_defaults();
+        }
+    }
+}
+
+impl<T> NormalizeDefaults for [T]
+where
+    T: NormalizeDefaults,
+{
+    fn normalize_defaults(&mut self) {
+        self.iter_mut().for_each(T::normalize_defaults);
+    }
+}
+
+impl<T, const N: usize> NormalizeDefaults for [T; N]
+where
+    T: NormalizeDefaults,
+{
+    fn normalize_defaults(&mut self) {
+        self.as_mut_slice().normalize_defaults();
+    }
+}
+
+impl<T> NormalizeDefaults for &mut T
+where
+    T: NormalizeDefaults + ?Sized,
+{
+    fn normalize_defaults(&mut self) {
+        (**self).normalize_defaults();
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_types {
+    use super::NormalizeDefaults;
+    use crate::IsDefault;
+    use std::{
+        collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+        ffi::OsString,
+        hash::{BuildHasher, Hash},
+        path::PathBuf,
+    };
+
+    noop_impl!(String, OsString, PathBuf);
+
+    impl<T> NormalizeDefaults for Box<T>
+    where
+        T: NormalizeDefaults + ?Sized,
+    {
+        fn normalize_defaults(&mut self) {
+            (**self).normalize_defaults();
+        }
+    }
+
+    impl<T> NormalizeDefaults for Vec<T>
+    where
+        T: NormalizeDefaults,
+    {
+        fn normalize_defaults(&mut self) {
+            self.as_mut_slice().normalize_defaults();
+        }
+    }
+
+    impl<T> NormalizeDefaults for VecDeque<T>
+    where
+        T: NormalizeDefaults,
+    {
+        fn normalize_defaults(&mut self) {
+            self.iter_mut().for_each(T::normalize_defaults);
+        }
+    }
+
+    /// Elements of sets cannot be changed in place, so this does nothing.
+    impl<T> NormalizeDefaults for BTreeSet<T> {
+        #[inline(always)]
+        fn normalize_defaults(&mut self) {}
+    }
+
+    /// Elements of sets cannot be changed in place, so this does nothing.
+    impl<T, S> NormalizeDefaults for HashSet<T, S> {
+        #[inline(always)]
+        fn normalize_defaults(&mut self) {}
+    }
+
+    /// Normalizes the values, then removes entries whose value is default.
+    impl<K, V> NormalizeDefaults for BTreeMap<K, V>
+    where
+        K: Ord,
+        V: NormalizeDefaults + IsDefault,
+    {
+        fn normalize_defaults(&mut self) {
+            self.retain(|_, v| {
+                v.normalize_defaults();
+                !v.is_default()
+            });
+        }
+    }
+
+    /// Normalizes the values, then removes entries whose value is default.
+    impl<K, V, S> NormalizeDefaults for HashMap<K, V, S>
+    where
+        K: Eq + Hash,
+        V: NormalizeDefaults + IsDefault,
+        S: BuildHasher,
+    {
+        fn normalize_defaults(&mut self) {
+            self.retain(|_, v| {
+                v.normalize_defaults();
+                !v.is_default()
+            });
+        }
+    }
+}
@@ -20,6 +20,21 @@ macro_rules! matches_impl {
             }
         }
     };
+    ($t:ty, $v:expr, $slice:path) => {
+        impl crate::IsDefault for $t {
+            #[doc = concat!("Returns `true` if self is `", stringify!($v), "`.")]
+            #[inline]
+            fn is_default(&self) -> bool {
+                matches!(self, $v)
+            }
+
+            /// Returns `true` if all elements are default, checking chunks at once.
+            #[inline]
+            fn is_default_slice(slice: &[Self]) -> bool {
+                $slice(slice)
+            }
+        }
+    };
 }
 
 macro_rules! is_empty_impl {
@@ -50,5 +65,13 @@ macro_rules! ref_impl {
 }
 
 mod core_types;
+mod slice;
 #[cfg(feature = "std")]
 mod std_types;
+
+#[cfg(feature = "serde_json")]
+mod feature_serde_json;
+#[cfg(feature = "serde_yaml")]
+mod feature_serde_yaml;
+#[cfg(feature = "toml")]
+mod feature_toml;
@@ -1,30 +1,37 @@
 use crate::IsDefault;
 
-matches_impl!(bool, false);
+impl IsDefault for bool {
+    /// Returns `true` if self is `false`.
+    #[inline]
+    fn is_default(&self) -> bool {
+        !*self
+    }
+}
+
 matches_impl!(char, '\x00');
 
 #[cfg(feature = "f16")]
 matches_impl!(f16, 0f16);
 
-matches_impl!(f32, 0f32);
-matches_impl!(f64, 0f64);
+matches_impl!(f32, 0f32, super::slice::f32s);
+matches_impl!(f64, 0f64, super::slice::f64s);
 
 #[cfg(feature = "f128")]
 matches_impl!(f128, 0f128);
 
-matches_impl!(i8, 0i8);
-matches_impl!(i16, 0i16);
-matches_impl!(i32, 0i32);
-matches_impl!(i64, 0i64);
-matches_impl!(i128, 0i128);
-matches_impl!(isize, 0isize);
+matches_impl!(i8, 0i8, super::slice::i8s);
+matches_impl!(i16, 0i16, super::slice::i16s);
+matches_impl!(i32, 0i32, super::slice::i32s);
+matches_impl!(i64, 0i64, super::slice::i64s);
+matches_impl!(i128, 0i128, super::slice::i128s);
+matches_impl!(isize, 0isize, super::slice::isizes);
 
-matches_impl!(u8, 0u8);
-matches_impl!(u16, 0u16);
-matches_impl!(u32, 0u32);
-matches_impl!(u64, 0u64);
-matches_impl!(u128, 0u128);
-matches_impl!(usize, 0usize);
+matches_impl!(u8, 0u8, super::slice::u8s);
+matches_impl!(u16, 0u16, super::slice::u16s);
+matches_impl!(u32, 0u32, super::slice::u32s);
+matches_impl!(u64, 0u64, super::slice::u64s);
+matches_impl!(u128, 0u128, super::slice::u128s);
+matches_impl!(usize, 0usize, super::slice::usizes);
 
 is_empty_impl!(str);
 
@@ -40,11 +47,7 @@ where
     /// - all slice elements is default
     #[inline]
     fn is_default(&self) -> bool {
-        if self.is_empty() {
-            true
-        } else {
-            self.iter().all(|x| x.is_default())
-        }
+        T::is_default_slice(self)
     }
 }
 
@@ -83,21 +86,6 @@ macro_rules! tuple_impls {
     };
 }
 
-// If this is a unary tuple, it adds a doc comment.
-// Otherwise, it hides the docs entirely.
-macro_rules! maybe_tuple_doc {
-    ($a:ident @ #[$meta:meta] $item:item) => {
-        #[doc = "This trait is implemented for tuples up to twelve items long."]
-        #[$meta]
-        $item
-    };
-    ($a:ident $($rest_a:ident)+ @ #[$meta:meta] $item:item) => {
-        #[doc(hidden)]
-        #[$meta]
-        $item
-    };
-}
-
 tuple_impls!(K J I H G F E D C B A T);
 
 #[cfg(feature = "ascii_char")]
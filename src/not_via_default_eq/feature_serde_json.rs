@@ -0,0 +1,18 @@
+use crate::IsDefault;
+use serde_json::{Map, Value};
+
+impl IsDefault for Value {
+    /// Returns `true` if self is `Value::Null`.
+    #[inline]
+    fn is_default(&self) -> bool {
+        self.is_null()
+    }
+}
+
+impl IsDefault for Map<String, Value> {
+    /// Returns `true` if self is empty.
+    #[inline]
+    fn is_default(&self) -> bool {
+        self.is_empty()
+    }
+}
@@ -0,0 +1,12 @@
+use crate::IsDefault;
+use serde_yaml::{Mapping, Value};
+
+impl IsDefault for Value {
+    /// Returns `true` if self is `Value::Null`.
+    #[inline]
+    fn is_default(&self) -> bool {
+        self.is_null()
+    }
+}
+
+is_empty_impl!(Mapping);
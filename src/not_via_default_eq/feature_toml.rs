@@ -0,0 +1,20 @@
+use crate::IsDefault;
+use toml::{Table, Value};
+
+impl IsDefault for Value {
+    /// Returns `true` if self is an empty array or table.
+    ///
+    /// `Value` has no `Default` and TOML has no `null`, so scalars such as
+    /// `0` or `false` are never default. They are explicit values, like
+    /// `0` in a `serde_json::Value`.
+    #[inline]
+    fn is_default(&self) -> bool {
+        match self {
+            Value::Array(v) => v.is_empty(),
+            Value::Table(v) => v.is_empty(),
+            _ => false,
+        }
+    }
+}
+
+is_empty_impl!(Table);
@@ -0,0 +1,126 @@
+//! Bulk default checks for slices of primitives.
+//!
+//! Elements are OR-reduced in fixed-size chunks, which the compiler turns
+//! into vector instructions, and the scan stops at the first non-zero
+//! chunk. Floats drop the sign bit first, since `-0.0` is default too. With
+//! the `portable_simd` feature, `core::simd` is used for types it supports.
+
+/// Elements reduced at once before checking for a non-default value.
+const CHUNK: usize = 64;
+
+mod scalar {
+    use super::CHUNK;
+
+    macro_rules! int_fns {
+        ($($name:ident: $t:ty),* $(,)?) => {
+            $(
+                pub fn $name(slice: &[$t]) -> bool {
+                    let zero = |chunk: &[$t]| chunk.iter().fold(0, |acc, &x| acc | x) == 0;
+                    let mut chunks = slice.chunks_exact(CHUNK);
+                    chunks.by_ref().all(zero) && zero(chunks.remainder())
+                }
+            )*
+        };
+    }
+
+    macro_rules! float_fns {
+        ($($name:ident: $t:ty, $mask:expr),* $(,)?) => {
+            $(
+                pub fn $name(slice: &[$t]) -> bool {
+                    let zero = |chunk: &[$t]| {
+                        chunk.iter().fold(0, |acc, x| acc | x.to_bits()) & $mask == 0
+                    };
+                    let mut chunks = slice.chunks_exact(CHUNK);
+                    chunks.by_ref().all(zero) && zero(chunks.remainder())
+                }
+            )*
+        };
+    }
+
+    int_fns!(
+        u8s: u8,
+        u16s: u16,
+        u32s: u32,
+        u64s: u64,
+        u128s: u128,
+        usizes: usize,
+        i8s: i8,
+        i16s: i16,
+        i32s: i32,
+        i64s: i64,
+        i128s: i128,
+        isizes: isize,
+    );
+
+    float_fns!(
+        f32s: f32, !(1u32 << 31),
+        f64s: f64, !(1u64 << 63),
+    );
+}
+
+#[cfg(feature = "portable_simd")]
+mod simd {
+    use super::{CHUNK, scalar};
+    use core::simd::{Simd, cmp::SimdPartialEq, num::SimdFloat};
+
+    macro_rules! int_fns {
+        ($($name:ident: $t:ty, $lanes:literal),* $(,)?) => {
+            $(
+                pub fn $name(slice: &[$t]) -> bool {
+                    let (prefix, middle, suffix) = slice.as_simd::<$lanes>();
+                    let zero = Simd::splat(0);
+                    scalar::$name(prefix)
+                        && scalar::$name(suffix)
+                        && middle
+                            .chunks(CHUNK / $lanes)
+                            .all(|c| c.iter().fold(zero, |acc, &v| acc | v).simd_eq(zero).all())
+                }
+            )*
+        };
+    }
+
+    macro_rules! float_fns {
+        ($($name:ident: $t:ty, $lanes:literal, $mask:expr),* $(,)?) => {
+            $(
+                pub fn $name(slice: &[$t]) -> bool {
+                    let (prefix, middle, suffix) = slice.as_simd::<$lanes>();
+                    let zero = Simd::splat(0);
+                    let mask = Simd::splat($mask);
+                    scalar::$name(prefix)
+                        && scalar::$name(suffix)
+                        && middle.chunks(CHUNK / $lanes).all(|c| {
+                            (c.iter().fold(zero, |acc, v| acc | v.to_bits()) & mask)
+                                .simd_eq(zero)
+                                .all()
+                        })
+                }
+            )*
+        };
+    }
+
+    int_fns!(
+        u8s: u8, 64,
+        u16s: u16, 32,
+        u32s: u32, 16,
+        u64s: u64, 8,
+        usizes: usize, 8,
+        i8s: i8, 64,
+        i16s: i16, 32,
+        i32s: i32, 16,
+        i64s: i64, 8,
+        isizes: isize, 8,
+    );
+
+    float_fns!(
+        f32s: f32, 16, !(1u32 << 31),
+        f64s: f64, 8, !(1u64 << 63),
+    );
+}
+
+pub use scalar::{i128s, u128s};
+
+#[cfg(not(feature = "portable_simd"))]
+pub use scalar::{f32s, f64s, i8s, i16s, i32s, i64s, isizes, u8s, u16s, u32s, u64s, usizes};
+
+#[cfg(feature = "portable_simd")]
+pub use simd::{f32s, f64s, i8s, i16s, i32s, i64s, isizes, u8s, u16s, u32s, u64s, usizes};
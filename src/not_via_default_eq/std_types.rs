@@ -46,7 +46,13 @@ macro_rules! atomic_impl {
     };
 }
 
-atomic_impl!(AtomicBool, false);
+impl IsDefault for AtomicBool {
+    /// Returns `true` if self is `false`.
+    #[inline]
+    fn is_default(&self) -> bool {
+        !self.load(Ordering::Relaxed)
+    }
+}
 
 atomic_impl!(AtomicI8, 0i8);
 atomic_impl!(AtomicI16, 0i16);
@@ -198,7 +204,7 @@ macro_rules! once_impl {
             /// Returns `true` if self is uninitialized.
             #[inline]
             fn is_default(&self) -> bool {
-                matches!(self.get(), None)
+                self.get().is_none()
             }
         }
     };
@@ -211,7 +217,7 @@ impl<T: ?Sized> IsDefault for rc::Weak<T> {
     /// Returns true if the [Weak::upgrade](rc::Weak::upgrade) returns `None`.
     #[inline]
     fn is_default(&self) -> bool {
-        matches!(self.upgrade(), None)
+        self.upgrade().is_none()
     }
 }
 
@@ -219,7 +225,7 @@ impl<T: ?Sized> IsDefault for sync::Weak<T> {
     /// Returns true if the [Weak::upgrade](sync::Weak::upgrade) returns `None`.
     #[inline]
     fn is_default(&self) -> bool {
-        matches!(self.upgrade(), None)
+        self.upgrade().is_none()
     }
 }
 
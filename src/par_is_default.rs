@@ -0,0 +1,178 @@
+use crate::IsDefault;
+use rayon::prelude::*;
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    hash::{BuildHasher, Hash},
+};
+
+/// Elements checked by one task in [`ParIsDefault::par_is_default`].
+const CHUNK: usize = 4096;
+
+/// Parallel, element-wise default checks with `rayon`.
+///
+/// Unlike `IsDefault` for collections, which checks for emptiness, these
+/// methods look at every element, or every value of a map. The checks stop
+/// as soon as any worker finds a non-default element.
+pub trait ParIsDefault {
+    /// Where a non-default element was found: an index for sequences, or a
+    /// key for maps.
+    type Position<'a>
+    where
+        Self: 'a;
+
+    /// Returns `true` if all elements are default.
+    fn par_is_default(&self) -> bool;
+
+    /// Returns the number of non-default elements.
+    fn par_count_non_default(&self) -> usize;
+
+    /// Returns the position of a non-default element, if any.
+    ///
+    /// For sequences, this is the index of the first one. For maps, it is
+    /// the key of any one.
+    fn par_position_non_default(&self) -> Option<Self::Position<'_>>;
+}
+
+impl<T> ParIsDefault for [T]
+where
+    T: IsDefault + Sync,
+{
+    type Position<'a>
+        = usize
+    where
+        T: 'a;
+
+    fn par_is_default(&self) -> bool {
+        self.par_chunks(CHUNK).all(T::is_default_slice)
+    }
+
+    fn par_count_non_default(&self) -> usize {
+        self.par_iter().filter(|v| !v.is_default()).count()
+    }
+
+    fn par_position_non_default(&self) -> Option<usize> {
+        self.par_iter().position_first(|v| !v.is_default())
+    }
+}
+
+impl<T, const N: usize> ParIsDefault for [T; N]
+where
+    T: IsDefault + Sync,
+{
+    type Position<'a>
+        = usize
+    where
+        T: 'a;
+
+    fn par_is_default(&self) -> bool {
+        self.as_slice().par_is_default()
+    }
+
+    fn par_count_non_default(&self) -> usize {
+        self.as_slice().par_count_non_default()
+    }
+
+    fn par_position_non_default(&self) -> Option<usize> {
+        self.as_slice().par_position_non_default()
+    }
+}
+
+impl<T> ParIsDefault for Vec<T>
+where
+    T: IsDefault + Sync,
+{
+    type Position<'a>
+        = usize
+    where
+        T: 'a;
+
+    fn par_is_default(&self) -> bool {
+        self.as_slice().par_is_default()
+    }
+
+    fn par_count_non_default(&self) -> usize {
+        self.as_slice().par_count_non_default()
+    }
+
+    fn par_position_non_default(&self) -> Option<usize> {
+        self.as_slice().par_position_non_default()
+    }
+}
+
+impl<T> ParIsDefault for VecDeque<T>
+where
+    T: IsDefault + Sync,
+{
+    type Position<'a>
+        = usize
+    where
+        T: 'a;
+
+    fn par_is_default(&self) -> bool {
+        let (front, back) = self.as_slices();
+        front.par_is_default() && back.par_is_default()
+    }
+
+    fn par_count_non_default(&self) -> usize {
+        let (front, back) = self.as_slices();
+        front.par_count_non_default() + back.par_count_non_default()
+    }
+
+    fn par_position_non_default(&self) -> Option<usize> {
+        let (front, back) = self.as_slices();
+        front
+            .par_position_non_default()
+            .or_else(|| Some(front.len() + back.par_position_non_default()?))
+    }
+}
+
+impl<K, V, S> ParIsDefault for HashMap<K, V, S>
+where
+    K: Eq + Hash + Sync,
+    V: IsDefault + Sync,
+    S: BuildHasher + Sync,
+{
+    type Position<'a>
+        = &'a K
+    where
+        Self: 'a;
+
+    fn par_is_default(&self) -> bool {
+        self.par_iter().all(|(_, v)| v.is_default())
+    }
+
+    fn par_count_non_default(&self) -> usize {
+        self.par_iter().filter(|(_, v)| !v.is_default()).count()
+    }
+
+    fn par_position_non_default(&self) -> Option<&K> {
+        self.par_iter()
+            .find_any(|(_, v)| !v.is_default())
+            .map(|(k, _)| k)
+    }
+}
+
+impl<K, V> ParIsDefault for BTreeMap<K, V>
+where
+    K: Ord + Sync,
+    V: IsDefault + Sync,
+{
+    type Position<'a>
+        = &'a K
+    where
+        Self: 'a;
+
+    fn par_is_default(&self) -> bool {
+        self.par_iter().all(|(_, v)| v.is_default())
+    }
+
+    fn par_count_non_default(&self) -> usize {
+        self.par_iter().filter(|(_, v)| !v.is_default()).count()
+    }
+
+    fn par_position_non_default(&self) -> Option<&K> {
+        self.par_iter()
+            .find_any(|(_, v)| !v.is_default())
+            .map(|(k, _)| k)
+    }
+}
@@ -0,0 +1,189 @@
+use core::mem;
+
+/// A value that tells "explicitly set" apart from "unset".
+///
+/// `Set(T::default())` and `Unset` resolve to the same [`effective`]
+/// value, but only `Unset` is default, so overlays can tell whether a
+/// user wrote `retries = 0` or nothing at all.
+///
+/// With the `serde` feature, a missing or `null` value deserializes as
+/// `Unset` and any other value, even a default one, as `Set`. Combine it
+/// with `#[serde(skip_serializing_if = "Presence::is_unset")]` to omit unset
+/// fields.
+///
+/// [`effective`]: Presence::effective
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Presence<T> {
+    /// No value was given.
+    #[default]
+    Unset,
+    /// A value was given, possibly the default one.
+    Set(T),
+}
+
+impl<T> Presence<T> {
+    /// Returns `true` if a value was given.
+    #[inline]
+    pub const fn is_set(&self) -> bool {
+        matches!(self, Presence::Set(_))
+    }
+
+    /// Returns `true` if no value was given.
+    #[inline]
+    pub const fn is_unset(&self) -> bool {
+        matches!(self, Presence::Unset)
+    }
+
+    /// Returns a reference to the given value.
+    #[inline]
+    pub const fn get(&self) -> Option<&T> {
+        match self {
+            Presence::Set(v) => Some(v),
+            Presence::Unset => None,
+        }
+    }
+
+    /// Returns the given value, or `T::default()` if unset.
+    #[inline]
+    pub fn effective(&self) -> T
+    where
+        T: Clone + Default,
+    {
+        self.get().cloned().unwrap_or_default()
+    }
+
+    /// Sets the value, returning the old one.
+    #[inline]
+    pub fn set(&mut self, value: T) -> Option<T> {
+        mem::replace(self, Presence::Set(value)).into_option()
+    }
+
+    /// Unsets the value, returning the old one.
+    #[inline]
+    pub fn unset(&mut self) -> Option<T> {
+        mem::take(self).into_option()
+    }
+
+    /// Returns `self` if set, otherwise `other`.
+    ///
+    /// This overlays `self` on top of `other`.
+    #[inline]
+    pub fn or(self, other: Self) -> Self {
+        match self {
+            Presence::Set(_) => self,
+            Presence::Unset => other,
+        }
+    }
+
+    /// Converts into an `Option`.
+    #[inline]
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            Presence::Set(v) => Some(v),
+            Presence::Unset => None,
+        }
+    }
+}
+
+impl<T> From<Option<T>> for Presence<T> {
+    #[inline]
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(v) => Presence::Set(v),
+            None => Presence::Unset,
+        }
+    }
+}
+
+impl<T> From<Presence<T>> for Option<T> {
+    #[inline]
+    fn from(value: Presence<T>) -> Self {
+        value.into_option()
+    }
+}
+
+#[cfg(not(feature = "via_default_eq"))]
+mod not_via_default_eq {
+    use super::Presence;
+    use crate::{IsDefault, ResetDefault};
+
+    impl<T> IsDefault for Presence<T> {
+        /// Returns `true` if self is `Unset`, even for `Set(T::default())`.
+        #[inline]
+        fn is_default(&self) -> bool {
+            self.is_unset()
+        }
+    }
+
+    impl<T> ResetDefault for Presence<T> {
+        /// Sets self to `Unset`.
+        #[inline]
+        fn reset_default(&mut self) {
+            *self = Presence::Unset;
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod feature_serde {
+    use super::Presence;
+    use core::{fmt, marker::PhantomData};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Visitor};
+
+    impl<T> Serialize for Presence<T>
+    where
+        T: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self {
+                Presence::Set(v) => serializer.serialize_some(v),
+                Presence::Unset => serializer.serialize_none(),
+            }
+        }
+    }
+
+    struct PresenceVisitor<T>(PhantomData<T>);
+
+    impl<'de, T> Visitor<'de> for PresenceVisitor<T>
+    where
+        T: Deserialize<'de>,
+    {
+        type Value = Presence<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an optional value")
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E> {
+            Ok(Presence::Unset)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E> {
+            Ok(Presence::Unset)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            T::deserialize(deserializer).map(Presence::Set)
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for Presence<T>
+    where
+        T: Deserialize<'de>,
+    {
+        /// Deserializes through `deserialize_option`, so that a missing
+        /// struct field is `Unset` even without `#[serde(default)]`.
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_option(PresenceVisitor(PhantomData))
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::IsDefault;
+
+/// Recursively removes default values from dynamic value trees.
+pub trait PruneDefaults {
+    /// Removes default-valued object members and trailing default array
+    /// elements, at every depth of `self`.
+    ///
+    /// Nested values are pruned first, so a member is also removed if it
+    /// becomes default after pruning.
+    fn prune_defaults(&mut self);
+}
+
+impl<T> PruneDefaults for Vec<T>
+where
+    T: IsDefault + PruneDefaults,
+{
+    /// Prunes all elements, then removes trailing default elements.
+    fn prune_defaults(&mut self) {
+        self.iter_mut().for_each(PruneDefaults::prune_defaults);
+        let len = self
+            .iter()
+            .rposition(|v| !v.is_default())
+            .map_or(0, |i| i + 1);
+        self.truncate(len);
+    }
+}
+
+#[cfg(feature = "serde_json")]
+mod feature_serde_json {
+    use super::PruneDefaults;
+    use crate::IsDefault;
+    use serde_json::{Map, Value};
+
+    impl PruneDefaults for Value {
+        fn prune_defaults(&mut self) {
+            match self {
+                Value::Array(v) => v.prune_defaults(),
+                Value::Object(v) => v.prune_defaults(),
+                _ => {}
+            }
+        }
+    }
+
+    impl PruneDefaults for Map<String, Value> {
+        fn prune_defaults(&mut self) {
+            self.values_mut().for_each(PruneDefaults::prune_defaults);
+            self.retain(|_, v| !v.is_default());
+        }
+    }
+}
+
+#[cfg(feature = "serde_yaml")]
+mod feature_serde_yaml {
+    use super::PruneDefaults;
+    use crate::IsDefault;
+    use serde_yaml::{Mapping, Value};
+
+    impl PruneDefaults for Value {
+        fn prune_defaults(&mut self) {
+            match self {
+                Value::Sequence(v) => v.prune_defaults(),
+                Value::Mapping(v) => v.prune_defaults(),
+                Value::Tagged(v) => v.value.prune_defaults(),
+                _ => {}
+            }
+        }
+    }
+
+    impl PruneDefaults for Mapping {
+        fn prune_defaults(&mut self) {
+            self.values_mut().for_each(PruneDefaults::prune_defaults);
+            self.retain(|_, v| !v.is_default());
+        }
+    }
+}
+
+#[cfg(all(feature = "toml", not(feature = "via_default_eq")))]
+mod feature_toml {
+    use super::PruneDefaults;
+    use crate::IsDefault;
+    use toml::{Table, Value};
+
+    impl PruneDefaults for Value {
+        fn prune_defaults(&mut self) {
+            match self {
+                Value::Array(v) => v.prune_defaults(),
+                Value::Table(v) => v.prune_defaults(),
+                _ => {}
+            }
+        }
+    }
+
+    impl PruneDefaults for Table {
+        fn prune_defaults(&mut self) {
+            self.iter_mut().for_each(|(_, v)| v.prune_defaults());
+            self.retain(|_, v| !v.is_default());
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::IsDefault;
+
+/// Resets a value to its type's default in place.
+pub trait ResetDefault: IsDefault {
+    /// Resets `self` to the default value for its type.
+    ///
+    /// Implementations must ensure that `self.is_default()` holds afterwards,
+    /// and should keep existing allocations where possible.
+    fn reset_default(&mut self);
+
+    /// Takes the value out of `self` if it is not default, leaving the
+    /// default value in its place.
+    ///
+    /// Returns `None` if `self` is already default.
+    #[inline]
+    fn take_if_non_default(&mut self) -> Option<Self>
+    where
+        Self: Default,
+    {
+        if self.is_default() {
+            None
+        } else {
+            Some(core::mem::take(self))
+        }
+    }
+}
+
+#[cfg(not(feature = "via_default_eq"))]
+macro_rules! noop_impl {
+    ($t:ty) => {
+        impl crate::ResetDefault for $t {
+            /// Does nothing.
+            #[inline(always)]
+            fn reset_default(&mut self) {}
+        }
+    };
+}
+
+#[cfg(not(feature = "via_default_eq"))]
+macro_rules! assign_impl {
+    ($t:ty, $v:expr) => {
+        impl crate::ResetDefault for $t {
+            #[doc = concat!("Sets self to `", stringify!($v), "`.")]
+            #[inline]
+            fn reset_default(&mut self) {
+                *self = $v;
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "std", not(feature = "via_default_eq")))]
+macro_rules! clear_impl {
+    ($t:ty) => {
+        impl crate::ResetDefault for $t {
+            /// Clears self, keeping the allocated capacity.
+            #[inline]
+            fn reset_default(&mut self) {
+                self.clear();
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "via_default_eq"))]
+mod core_types;
+#[cfg(all(feature = "std", not(feature = "via_default_eq")))]
+mod std_types;
+
+#[cfg(feature = "via_default_eq")]
+mod via_default_eq {
+    use crate::ResetDefault;
+
+    impl<T> ResetDefault for T
+    where
+        T: Default + PartialEq,
+    {
+        fn reset_default(&mut self) {
+            *self = Self::default();
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::ResetDefault;
+
+assign_impl!(bool, false);
+assign_impl!(char, '\x00');
+
+#[cfg(feature = "f16")]
+assign_impl!(f16, 0f16);
+
+assign_impl!(f32, 0f32);
+assign_impl!(f64, 0f64);
+
+#[cfg(feature = "f128")]
+assign_impl!(f128, 0f128);
+
+assign_impl!(i8, 0i8);
+assign_impl!(i16, 0i16);
+assign_impl!(i32, 0i32);
+assign_impl!(i64, 0i64);
+assign_impl!(i128, 0i128);
+assign_impl!(isize, 0isize);
+
+assign_impl!(u8, 0u8);
+assign_impl!(u16, 0u16);
+assign_impl!(u32, 0u32);
+assign_impl!(u64, 0u64);
+assign_impl!(u128, 0u128);
+assign_impl!(usize, 0usize);
+
+impl<T> ResetDefault for &mut T
+where
+    T: ResetDefault + ?Sized,
+{
+    /// Resets the referenced value.
+    #[inline]
+    fn reset_default(&mut self) {
+        (**self).reset_default()
+    }
+}
+
+impl<T> ResetDefault for [T]
+where
+    T: ResetDefault,
+{
+    /// Resets all slice elements.
+    #[inline]
+    fn reset_default(&mut self) {
+        self.iter_mut().for_each(|x| x.reset_default())
+    }
+}
+
+impl<T, const N: usize> ResetDefault for [T; N]
+where
+    T: ResetDefault,
+{
+    /// Resets all array elements.
+    #[inline]
+    fn reset_default(&mut self) {
+        self.as_mut_slice().reset_default()
+    }
+}
+
+macro_rules! tuple_impls {
+    () => {
+        noop_impl!(());
+    };
+
+    ($T:ident $( $Ts:ident)*) => {
+        maybe_tuple_doc! {
+            $T $($Ts)* @
+            #[allow(non_snake_case)]
+            impl<$T: ResetDefault, $($Ts: ResetDefault),*> ResetDefault for ($T, $($Ts,)*) {
+                /// Resets all tuple fields.
+                fn reset_default(&mut self) {
+                    let ($T, $($Ts,)*) = self;
+                    $T.reset_default(); $( $Ts.reset_default(); )*
+                }
+            }
+        }
+
+        tuple_impls!($($Ts)*);
+    };
+}
+
+tuple_impls!(K J I H G F E D C B A T);
+
+#[cfg(feature = "ascii_char")]
+mod ascii_char {
+    use core::ascii::Char;
+
+    assign_impl!(Char, Char::Null);
+}
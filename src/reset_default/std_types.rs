@@ -0,0 +1,273 @@
+use crate::{IsDefault, ResetDefault};
+use std::{
+    borrow::Cow,
+    cell::{Cell, OnceCell, RefCell, RefMut},
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
+    ffi::{CString, OsString},
+    io::{Cursor, Empty, Sink},
+    marker::PhantomPinned,
+    num::Wrapping,
+    path::PathBuf,
+    rc::{self, Rc},
+    sync::{
+        self, Arc, Mutex, OnceLock, PoisonError, RwLock,
+        atomic::{
+            AtomicBool, AtomicI8, AtomicI16, AtomicI32, AtomicI64, AtomicIsize, AtomicU8,
+            AtomicU16, AtomicU32, AtomicU64, AtomicUsize,
+        },
+    },
+    time::Duration,
+};
+
+impl<T> ResetDefault for Wrapping<T>
+where
+    T: ResetDefault,
+{
+    /// Resets the inner value.
+    #[inline]
+    fn reset_default(&mut self) {
+        self.0.reset_default()
+    }
+}
+
+noop_impl!(Empty);
+noop_impl!(PhantomPinned);
+noop_impl!(Sink);
+
+macro_rules! atomic_impl {
+    ($t:ty, $v:expr) => {
+        impl ResetDefault for $t {
+            #[doc = concat!("Sets self to `", stringify!($v), "`.")]
+            #[inline]
+            fn reset_default(&mut self) {
+                *self.get_mut() = $v;
+            }
+        }
+    };
+}
+
+atomic_impl!(AtomicBool, false);
+
+atomic_impl!(AtomicI8, 0i8);
+atomic_impl!(AtomicI16, 0i16);
+atomic_impl!(AtomicI32, 0i32);
+atomic_impl!(AtomicI64, 0i64);
+atomic_impl!(AtomicIsize, 0isize);
+
+atomic_impl!(AtomicU8, 0u8);
+atomic_impl!(AtomicU16, 0u16);
+atomic_impl!(AtomicU32, 0u32);
+atomic_impl!(AtomicU64, 0u64);
+atomic_impl!(AtomicUsize, 0usize);
+
+clear_impl!(String);
+clear_impl!(OsString);
+
+impl ResetDefault for CString {
+    /// Sets self to an empty `CString`.
+    #[inline]
+    fn reset_default(&mut self) {
+        *self = CString::default();
+    }
+}
+
+impl ResetDefault for PathBuf {
+    /// Clears self, keeping the allocated capacity.
+    #[inline]
+    fn reset_default(&mut self) {
+        self.as_mut_os_string().clear();
+    }
+}
+
+assign_impl!(Duration, Duration::ZERO);
+
+impl<T> ResetDefault for Option<T> {
+    /// Sets self to `None`.
+    #[inline]
+    fn reset_default(&mut self) {
+        *self = None;
+    }
+}
+
+macro_rules! clear_impl_t {
+    ($t:ident) => {
+        impl<T> ResetDefault for $t<T> {
+            /// Clears self, keeping the allocated capacity.
+            #[inline]
+            fn reset_default(&mut self) {
+                self.clear();
+            }
+        }
+    };
+}
+
+clear_impl_t!(BTreeSet);
+clear_impl_t!(BinaryHeap);
+clear_impl_t!(HashSet);
+clear_impl_t!(LinkedList);
+clear_impl_t!(Vec);
+clear_impl_t!(VecDeque);
+
+macro_rules! clear_impl_k_v {
+    ($t:ident) => {
+        impl<K, V> ResetDefault for $t<K, V> {
+            /// Clears self, keeping the allocated capacity.
+            #[inline]
+            fn reset_default(&mut self) {
+                self.clear();
+            }
+        }
+    };
+}
+
+clear_impl_k_v!(BTreeMap);
+clear_impl_k_v!(HashMap);
+
+impl<T> ResetDefault for Box<T>
+where
+    T: ResetDefault + ?Sized,
+{
+    /// Resets the inner value.
+    #[inline]
+    fn reset_default(&mut self) {
+        (**self).reset_default()
+    }
+}
+
+macro_rules! shared_impl_t {
+    ($t:ident) => {
+        impl<T> ResetDefault for $t<T>
+        where
+            T: ResetDefault + Default,
+        {
+            /// Resets the inner value if self is its only owner.
+            #[doc = concat!("Otherwise, replaces self with a new `", stringify!($t), "` of the default value.")]
+            #[inline]
+            fn reset_default(&mut self) {
+                match $t::get_mut(self) {
+                    Some(v) => v.reset_default(),
+                    None => *self = $t::default(),
+                }
+            }
+        }
+    };
+}
+
+shared_impl_t!(Arc);
+shared_impl_t!(Rc);
+
+impl<T> ResetDefault for Cow<'_, T>
+where
+    T: IsDefault + ToOwned + ?Sized,
+    T::Owned: ResetDefault + Default,
+{
+    /// Resets the owned value, or replaces the borrowed one with
+    /// an owned default value.
+    #[inline]
+    fn reset_default(&mut self) {
+        match self {
+            Cow::Owned(v) => v.reset_default(),
+            Cow::Borrowed(_) => *self = Cow::Owned(T::Owned::default()),
+        }
+    }
+}
+
+impl<T> ResetDefault for RefCell<T>
+where
+    T: ResetDefault + ?Sized,
+{
+    /// Resets the inner value.
+    #[inline]
+    fn reset_default(&mut self) {
+        self.get_mut().reset_default()
+    }
+}
+
+macro_rules! lock_impl {
+    ($t:ident) => {
+        impl<T> ResetDefault for $t<T>
+        where
+            T: ResetDefault + ?Sized,
+        {
+            /// Resets the inner value and clears the poisoned state.
+            #[inline]
+            fn reset_default(&mut self) {
+                self.get_mut()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .reset_default();
+                self.clear_poison();
+            }
+        }
+    };
+}
+
+lock_impl!(RwLock);
+lock_impl!(Mutex);
+
+impl<T> ResetDefault for Cell<T>
+where
+    T: Copy + ResetDefault,
+{
+    /// Resets the inner value.
+    #[inline]
+    fn reset_default(&mut self) {
+        self.get_mut().reset_default()
+    }
+}
+
+macro_rules! once_impl {
+    ($t:ident) => {
+        impl<T> ResetDefault for $t<T> {
+            /// Takes the value out, leaving self uninitialized.
+            #[inline]
+            fn reset_default(&mut self) {
+                self.take();
+            }
+        }
+    };
+}
+
+once_impl!(OnceCell);
+once_impl!(OnceLock);
+
+impl<T> ResetDefault for rc::Weak<T> {
+    /// Sets self to [Weak::new](rc::Weak::new).
+    #[inline]
+    fn reset_default(&mut self) {
+        *self = rc::Weak::new();
+    }
+}
+
+impl<T> ResetDefault for sync::Weak<T> {
+    /// Sets self to [Weak::new](sync::Weak::new).
+    #[inline]
+    fn reset_default(&mut self) {
+        *self = sync::Weak::new();
+    }
+}
+
+impl<T> ResetDefault for Cursor<T> {
+    /// Sets the cursor position to `0`.
+    #[inline]
+    fn reset_default(&mut self) {
+        self.set_position(0);
+    }
+}
+
+impl<T> ResetDefault for RefMut<'_, T>
+where
+    T: ResetDefault + ?Sized,
+{
+    /// Resets the borrowed value.
+    #[inline]
+    fn reset_default(&mut self) {
+        (**self).reset_default()
+    }
+}
+
+#[cfg(feature = "bstr")]
+mod feature_bstr {
+    use std::bstr::ByteString;
+
+    clear_impl!(ByteString);
+}
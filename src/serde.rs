@@ -0,0 +1,196 @@
+//! Adapters for [`serde_with`](https://docs.rs/serde_with).
+//!
+//! Use them with the `#[serde_as]` attribute:
+//!
+//! ```
+//! # #[cfg(all(feature = "std", not(feature = "via_default_eq")))] {
+//! use is_default::serde::{DefaultAsNull, SkipDefault};
+//! use serde::{Deserialize, Serialize};
+//! use serde_with::serde_as;
+//! use std::collections::BTreeMap;
+//!
+//! #[serde_as]
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde_as(as = "DefaultAsNull")]
+//!     #[serde(default)]
+//!     retries: u8,
+//!     #[serde_as(as = "SkipDefault")]
+//!     limits: BTreeMap<String, u32>,
+//! }
+//!
+//! let config = Config {
+//!     retries: 0,
+//!     limits: BTreeMap::from([("a".into(), 0), ("b".into(), 1)]),
+//! };
+//! let json = serde_json::to_string(&config).unwrap();
+//! assert_eq!(json, r#"{"retries":null,"limits":{"b":1}}"#);
+//! # }
+//! ```
+
+use crate::IsDefault;
+use core::marker::PhantomData;
+use serde::{Deserialize, Deserializer, Serializer};
+use serde_with::{DeserializeAs, Same, SerializeAs, de::DeserializeAsWrap, ser::SerializeAsWrap};
+
+/// Serializes a default value as `null`, and deserializes `null` as the
+/// default value.
+///
+/// Other values are (de)serialized with `U`. Combine it with `#[serde(default)]`
+/// to also deserialize a missing value as the default value.
+pub struct DefaultAsNull<U = Same>(PhantomData<U>);
+
+impl<T, U> SerializeAs<T> for DefaultAsNull<U>
+where
+    T: IsDefault,
+    U: SerializeAs<T>,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if source.is_default() {
+            serializer.serialize_none()
+        } else {
+            serializer.serialize_some(&SerializeAsWrap::<T, U>::new(source))
+        }
+    }
+}
+
+impl<'de, T, U> DeserializeAs<'de, T> for DefaultAsNull<U>
+where
+    T: Default,
+    U: DeserializeAs<'de, T>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let v = Option::<DeserializeAsWrap<T, U>>::deserialize(deserializer)?;
+        Ok(v.map(DeserializeAsWrap::into_inner).unwrap_or_default())
+    }
+}
+
+/// Skips default elements of sequences and default-valued entries of maps
+/// on serialization.
+///
+/// Elements, or map values, are (de)serialized with `U`. Deserialization
+/// accepts every element.
+pub struct SkipDefault<U = Same>(PhantomData<U>);
+
+#[cfg(feature = "std")]
+mod std_types {
+    use super::SkipDefault;
+    use crate::IsDefault;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_with::{DeserializeAs, SerializeAs, de::DeserializeAsWrap, ser::SerializeAsWrap};
+    use std::{
+        collections::{BTreeMap, HashMap, VecDeque},
+        hash::{BuildHasher, Hash},
+    };
+
+    macro_rules! seq_impl {
+        ($t:ident) => {
+            impl<T, U> SerializeAs<$t<T>> for SkipDefault<U>
+            where
+                T: IsDefault,
+                U: SerializeAs<T>,
+            {
+                fn serialize_as<S>(source: &$t<T>, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.collect_seq(
+                        source
+                            .iter()
+                            .filter(|v| !v.is_default())
+                            .map(SerializeAsWrap::<T, U>::new),
+                    )
+                }
+            }
+
+            impl<'de, T, U> DeserializeAs<'de, $t<T>> for SkipDefault<U>
+            where
+                U: DeserializeAs<'de, T>,
+            {
+                fn deserialize_as<D>(deserializer: D) -> Result<$t<T>, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let v = Vec::<DeserializeAsWrap<T, U>>::deserialize(deserializer)?;
+                    Ok(v.into_iter().map(DeserializeAsWrap::into_inner).collect())
+                }
+            }
+        };
+    }
+
+    seq_impl!(Vec);
+    seq_impl!(VecDeque);
+
+    impl<K, V, U> SerializeAs<BTreeMap<K, V>> for SkipDefault<U>
+    where
+        K: Serialize,
+        V: IsDefault,
+        U: SerializeAs<V>,
+    {
+        fn serialize_as<S>(source: &BTreeMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_map(
+                source
+                    .iter()
+                    .filter(|(_, v)| !v.is_default())
+                    .map(|(k, v)| (k, SerializeAsWrap::<V, U>::new(v))),
+            )
+        }
+    }
+
+    impl<'de, K, V, U> DeserializeAs<'de, BTreeMap<K, V>> for SkipDefault<U>
+    where
+        K: Deserialize<'de> + Ord,
+        U: DeserializeAs<'de, V>,
+    {
+        fn deserialize_as<D>(deserializer: D) -> Result<BTreeMap<K, V>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let m = BTreeMap::<K, DeserializeAsWrap<V, U>>::deserialize(deserializer)?;
+            Ok(m.into_iter().map(|(k, v)| (k, v.into_inner())).collect())
+        }
+    }
+
+    impl<K, V, H, U> SerializeAs<HashMap<K, V, H>> for SkipDefault<U>
+    where
+        K: Serialize,
+        V: IsDefault,
+        U: SerializeAs<V>,
+    {
+        fn serialize_as<S>(source: &HashMap<K, V, H>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_map(
+                source
+                    .iter()
+                    .filter(|(_, v)| !v.is_default())
+                    .map(|(k, v)| (k, SerializeAsWrap::<V, U>::new(v))),
+            )
+        }
+    }
+
+    impl<'de, K, V, H, U> DeserializeAs<'de, HashMap<K, V, H>> for SkipDefault<U>
+    where
+        K: Deserialize<'de> + Eq + Hash,
+        H: BuildHasher + Default,
+        U: DeserializeAs<'de, V>,
+    {
+        fn deserialize_as<D>(deserializer: D) -> Result<HashMap<K, V, H>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let m = HashMap::<K, DeserializeAsWrap<V, U>, H>::deserialize(deserializer)?;
+            Ok(m.into_iter().map(|(k, v)| (k, v.into_inner())).collect())
+        }
+    }
+}
@@ -0,0 +1,402 @@
+//! A compact binary encoding that omits default fields.
+//!
+//! A derived struct is encoded as a varint presence bitmap, with bit `i`
+//! set if the field with index `i` is not default, followed by the
+//! present fields in index order. Each present field is prefixed with its
+//! length, so decoders skip fields they don't know. Missing fields are
+//! decoded as their `Default` values.
+//!
+//! Field indices default to the declaration order and can be pinned with
+//! `#[sparse(index = N)]` to keep the encoding stable when fields are
+//! added, removed or reordered. At most 128 field indices are supported.
+//!
+//! Other values use fixed encodings:
+//! - `bool`, `u8` and `i8` as a single byte
+//! - other integers and `char` as LEB128 varints, signed ones zigzag-encoded
+//! - floats as little-endian bytes
+//! - strings and sequences as a varint length followed by their contents
+//! - `Option` as a `0` or `1` byte followed by the value
+
+use std::{error::Error, fmt};
+
+/// Encodes a value in the sparse binary format.
+pub trait SparseEncode {
+    /// Appends the encoding of `self` to `out`.
+    fn sparse_encode(&self, out: &mut Vec<u8>);
+
+    /// Returns the encoding of `self`.
+    fn to_sparse_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.sparse_encode(&mut out);
+        out
+    }
+}
+
+/// Decodes a value from the sparse binary format.
+pub trait SparseDecode: Sized {
+    /// Decodes a value from the start of `input`, advancing it past the
+    /// consumed bytes.
+    fn sparse_decode(input: &mut &[u8]) -> Result<Self, DecodeError>;
+
+    /// Decodes a value from `bytes`, which must hold exactly one value.
+    fn from_sparse_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        decode_exact(bytes)
+    }
+}
+
+/// An error returned when decoding fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input ended before the value was complete.
+    UnexpectedEof,
+    /// A varint does not fit into the target type.
+    VarintOverflow,
+    /// The input holds an invalid value for the target type.
+    InvalidValue,
+    /// The input has bytes left after the value.
+    TrailingBytes,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DecodeError::UnexpectedEof => "unexpected end of input",
+            DecodeError::VarintOverflow => "varint overflow",
+            DecodeError::InvalidValue => "invalid value",
+            DecodeError::TrailingBytes => "trailing bytes after value",
+        })
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Appends `v` to `out` as a LEB128 varint.
+pub fn encode_varint(mut v: u128, out: &mut Vec<u8>) {
+    while v >= 0x80 {
+        out.push(v as u8 | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+/// Decodes a LEB128 varint from the start of `input`.
+pub fn decode_varint(input: &mut &[u8]) -> Result<u128, DecodeError> {
+    let mut v = 0u128;
+    let mut shift = 0;
+
+    loop {
+        let (&byte, rest) = input.split_first().ok_or(DecodeError::UnexpectedEof)?;
+        *input = rest;
+
+        let bits = u128::from(byte & 0x7f);
+        if shift >= 128 || (shift > 121 && bits >> (128 - shift) != 0) {
+            return Err(DecodeError::VarintOverflow);
+        }
+        v |= bits << shift;
+
+        if byte & 0x80 == 0 {
+            return Ok(v);
+        }
+        shift += 7;
+    }
+}
+
+/// Appends a field to `out`, prefixed with its encoded length.
+pub fn encode_field<T>(value: &T, out: &mut Vec<u8>)
+where
+    T: SparseEncode + ?Sized,
+{
+    // Reserve one byte for the length, which fits fields shorter than 128
+    // bytes, so nested small fields are written in place. Longer fields are
+    // moved once to make room for the rest of the prefix.
+    let start = out.len();
+    out.push(0);
+    value.sparse_encode(out);
+
+    let len = out.len() - start - 1;
+    if len < 0x80 {
+        out[start] = len as u8;
+        return;
+    }
+
+    let mut prefix = Vec::new();
+    encode_varint(len as u128, &mut prefix);
+    let extra = prefix.len() - 1;
+    out.resize(out.len() + extra, 0);
+    out.copy_within(start + 1..start + 1 + len, start + 1 + extra);
+    out[start..=start + extra].copy_from_slice(&prefix);
+}
+
+/// Splits a length-prefixed field off the start of `input`.
+pub fn decode_field<'a>(input: &mut &'a [u8]) -> Result<&'a [u8], DecodeError> {
+    let len = decode_len(input)?;
+    take(input, len)
+}
+
+/// Decodes a value from `bytes`, which must hold exactly one value.
+pub fn decode_exact<T>(mut bytes: &[u8]) -> Result<T, DecodeError>
+where
+    T: SparseDecode,
+{
+    let v = T::sparse_decode(&mut bytes)?;
+    if bytes.is_empty() {
+        Ok(v)
+    } else {
+        Err(DecodeError::TrailingBytes)
+    }
+}
+
+fn decode_len(input: &mut &[u8]) -> Result<usize, DecodeError> {
+    usize::try_from(decode_varint(input)?).map_err(|_| DecodeError::VarintOverflow)
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], DecodeError> {
+    if input.len() < len {
+        return Err(DecodeError::UnexpectedEof);
+    }
+    let (head, rest) = input.split_at(len);
+    *input = rest;
+    Ok(head)
+}
+
+impl SparseEncode for bool {
+    fn sparse_encode(&self, out: &mut Vec<u8>) {
+        out.push(u8::from(*self));
+    }
+}
+
+impl SparseDecode for bool {
+    fn sparse_decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        match take(input, 1)? {
+            [0] => Ok(false),
+            [1] => Ok(true),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+macro_rules! byte_impl {
+    ($t:ty) => {
+        impl SparseEncode for $t {
+            fn sparse_encode(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+
+        impl SparseDecode for $t {
+            fn sparse_decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+                let bytes = take(input, size_of::<$t>())?;
+                Ok(<$t>::from_le_bytes(bytes.try_into().unwrap()))
+            }
+        }
+    };
+}
+
+byte_impl!(u8);
+byte_impl!(i8);
+byte_impl!(f32);
+byte_impl!(f64);
+
+macro_rules! unsigned_impl {
+    ($t:ty) => {
+        impl SparseEncode for $t {
+            fn sparse_encode(&self, out: &mut Vec<u8>) {
+                encode_varint(*self as u128, out);
+            }
+        }
+
+        impl SparseDecode for $t {
+            fn sparse_decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+                <$t>::try_from(decode_varint(input)?).map_err(|_| DecodeError::VarintOverflow)
+            }
+        }
+    };
+}
+
+unsigned_impl!(u16);
+unsigned_impl!(u32);
+unsigned_impl!(u64);
+unsigned_impl!(u128);
+unsigned_impl!(usize);
+
+macro_rules! signed_impl {
+    ($t:ty) => {
+        impl SparseEncode for $t {
+            fn sparse_encode(&self, out: &mut Vec<u8>) {
+                let v = *self as i128;
+                encode_varint(((v << 1) ^ (v >> 127)) as u128, out);
+            }
+        }
+
+        impl SparseDecode for $t {
+            fn sparse_decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+                let v = decode_varint(input)?;
+                let v = (v >> 1) as i128 ^ -((v & 1) as i128);
+                <$t>::try_from(v).map_err(|_| DecodeError::VarintOverflow)
+            }
+        }
+    };
+}
+
+signed_impl!(i16);
+signed_impl!(i32);
+signed_impl!(i64);
+signed_impl!(i128);
+signed_impl!(isize);
+
+impl SparseEncode for char {
+    fn sparse_encode(&self, out: &mut Vec<u8>) {
+        encode_varint(u128::from(u32::from(*self)), out);
+    }
+}
+
+impl SparseDecode for char {
+    fn sparse_decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let v = u32::sparse_decode(input)?;
+        char::from_u32(v).ok_or(DecodeError::InvalidValue)
+    }
+}
+
+impl SparseEncode for () {
+    fn sparse_encode(&self, _out: &mut Vec<u8>) {}
+}
+
+impl SparseDecode for () {
+    fn sparse_decode(_input: &mut &[u8]) -> Result<Self, DecodeError> {
+        Ok(())
+    }
+}
+
+impl SparseEncode for str {
+    fn sparse_encode(&self, out: &mut Vec<u8>) {
+        encode_varint(self.len() as u128, out);
+        out.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl SparseEncode for String {
+    fn sparse_encode(&self, out: &mut Vec<u8>) {
+        self.as_str().sparse_encode(out)
+    }
+}
+
+impl SparseDecode for String {
+    fn sparse_decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let len = decode_len(input)?;
+        let bytes = take(input, len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| DecodeError::InvalidValue)
+    }
+}
+
+impl<T> SparseEncode for [T]
+where
+    T: SparseEncode,
+{
+    fn sparse_encode(&self, out: &mut Vec<u8>) {
+        encode_varint(self.len() as u128, out);
+        self.iter().for_each(|v| v.sparse_encode(out));
+    }
+}
+
+impl<T> SparseEncode for Vec<T>
+where
+    T: SparseEncode,
+{
+    fn sparse_encode(&self, out: &mut Vec<u8>) {
+        self.as_slice().sparse_encode(out)
+    }
+}
+
+impl<T> SparseDecode for Vec<T>
+where
+    T: SparseDecode,
+{
+    fn sparse_decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let len = decode_len(input)?;
+        // Every element takes at least one byte of input, so cap the
+        // allocation at the memory of that many elements.
+        let cap = input.len() / size_of::<T>().max(1);
+        let mut v = Vec::with_capacity(len.min(cap));
+        for _ in 0..len {
+            v.push(T::sparse_decode(input)?);
+        }
+        Ok(v)
+    }
+}
+
+impl<T, const N: usize> SparseEncode for [T; N]
+where
+    T: SparseEncode,
+{
+    fn sparse_encode(&self, out: &mut Vec<u8>) {
+        self.iter().for_each(|v| v.sparse_encode(out));
+    }
+}
+
+impl<T, const N: usize> SparseDecode for [T; N]
+where
+    T: SparseDecode,
+{
+    fn sparse_decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        let mut v = Vec::with_capacity(N);
+        for _ in 0..N {
+            v.push(T::sparse_decode(input)?);
+        }
+        v.try_into().map_err(|_| DecodeError::InvalidValue)
+    }
+}
+
+impl<T> SparseEncode for Option<T>
+where
+    T: SparseEncode,
+{
+    fn sparse_encode(&self, out: &mut Vec<u8>) {
+        match self {
+            None => out.push(0),
+            Some(v) => {
+                out.push(1);
+                v.sparse_encode(out);
+            }
+        }
+    }
+}
+
+impl<T> SparseDecode for Option<T>
+where
+    T: SparseDecode,
+{
+    fn sparse_decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        match take(input, 1)? {
+            [0] => Ok(None),
+            [1] => T::sparse_decode(input).map(Some),
+            _ => Err(DecodeError::InvalidValue),
+        }
+    }
+}
+
+impl<T> SparseEncode for Box<T>
+where
+    T: SparseEncode + ?Sized,
+{
+    fn sparse_encode(&self, out: &mut Vec<u8>) {
+        (**self).sparse_encode(out)
+    }
+}
+
+impl<T> SparseDecode for Box<T>
+where
+    T: SparseDecode,
+{
+    fn sparse_decode(input: &mut &[u8]) -> Result<Self, DecodeError> {
+        T::sparse_decode(input).map(Box::new)
+    }
+}
+
+impl<T> SparseEncode for &T
+where
+    T: SparseEncode + ?Sized,
+{
+    fn sparse_encode(&self, out: &mut Vec<u8>) {
+        (**self).sparse_encode(out)
+    }
+}
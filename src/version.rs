@@ -0,0 +1,94 @@
+//! Versions for `#[is_default(since = "...")]` fields.
+
+use core::{error::Error, fmt, str::FromStr};
+
+/// A `major.minor.patch` version.
+///
+/// Parsed from `"1"`, `"1.4"` or `"1.4.2"`, with missing parts set to zero.
+/// Versions are ordered by major, then minor, then patch.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    /// The major version, for incompatible changes.
+    pub major: u64,
+    /// The minor version, for compatible additions.
+    pub minor: u64,
+    /// The patch version, for compatible fixes.
+    pub patch: u64,
+}
+
+impl Version {
+    /// Creates a version from its parts.
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Parses a version in const context, like [`FromStr`].
+    ///
+    /// The derive uses this for `#[is_default(since = "...")]`, so invalid
+    /// versions fail to compile.
+    pub const fn parse(s: &str) -> Result<Self, ParseVersionError> {
+        let bytes = s.as_bytes();
+        let mut parts = [0u64; 3];
+        let mut part = 0;
+        let mut digits = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let b = bytes[i];
+            if b == b'.' {
+                if digits == 0 || part == 2 {
+                    return Err(ParseVersionError);
+                }
+                part += 1;
+                digits = 0;
+            } else if b.is_ascii_digit() {
+                parts[part] = match parts[part].checked_mul(10) {
+                    Some(v) => match v.checked_add((b - b'0') as u64) {
+                        Some(v) => v,
+                        None => return Err(ParseVersionError),
+                    },
+                    None => return Err(ParseVersionError),
+                };
+                digits += 1;
+            } else {
+                return Err(ParseVersionError);
+            }
+            i += 1;
+        }
+
+        if digits == 0 {
+            return Err(ParseVersionError);
+        }
+        Ok(Self::new(parts[0], parts[1], parts[2]))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// An error returned when parsing a [`Version`] fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseVersionError;
+
+impl fmt::Display for ParseVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid version, expected `major[.minor[.patch]]`")
+    }
+}
+
+impl Error for ParseVersionError {}
+
+impl FromStr for Version {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
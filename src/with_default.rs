@@ -0,0 +1,289 @@
+//! Primitive wrappers with a non-zero default value.
+//!
+//! A field like `port: u16` that should default to `8080` otherwise needs
+//! a hand-written `Default` impl, which a derived `IsDefault` knows nothing
+//! about. `WithDefault<u16, 8080>` carries the default value in its type,
+//! so `Default`, `IsDefault` and `ResetDefault` all agree on it, and both
+//! `#[derive(Default, IsDefault)]` work unchanged:
+//!
+//! ```
+//! # #[cfg(all(feature = "derive", not(feature = "via_default_eq")))] {
+//! use is_default::{IsDefault, WithDefault, WithDefaultBool};
+//!
+//! #[derive(Default, IsDefault)]
+//! struct Server {
+//!     port: WithDefault<u16, 8080>,
+//!     tls: WithDefaultBool<true>,
+//! }
+//!
+//! let mut server = Server::default();
+//! assert_eq!(*server.port, 8080);
+//! assert!(server.is_default());
+//!
+//! *server.port = 0;
+//! assert!(!server.is_default());
+//! # }
+//! ```
+//!
+//! [`WithDefault`] takes the default as an `i128`, so it covers all integer
+//! types with a single generic parameter. Out-of-range values fail at
+//! compile time. The typed wrappers, like `WithDefaultI64<-1>`, take a
+//! parameter of the exact type instead, and also cover `bool`, `char` and
+//! `u128` values above `i128::MAX`.
+//!
+//! With the `serde` feature, all wrappers (de)serialize as the inner value.
+
+use crate::ConstDefault;
+use core::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Integer types usable in [`WithDefault`].
+///
+/// This trait is sealed and implemented for all primitive integer types.
+pub trait Integer: sealed::Sealed + Copy + PartialEq {
+    /// The smallest value of the type, as an `i128`.
+    #[doc(hidden)]
+    const MIN: i128;
+    /// The largest value of the type, as an `i128`.
+    #[doc(hidden)]
+    const MAX: i128;
+
+    /// Converts a value within `MIN..=MAX`.
+    #[doc(hidden)]
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! integer_impl {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Integer for $t {
+                const MIN: i128 = <$t>::MIN as i128;
+                const MAX: i128 = if <$t>::MAX as u128 > i128::MAX as u128 {
+                    i128::MAX
+                } else {
+                    <$t>::MAX as i128
+                };
+
+                #[inline(always)]
+                fn from_i128(value: i128) -> Self {
+                    value as $t
+                }
+            }
+        )*
+    };
+}
+
+integer_impl!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// An integer whose default value is `V`.
+///
+/// `V` is checked against the range of `T` when the default value is first
+/// used:
+///
+/// ```compile_fail
+/// let _ = is_default::WithDefault::<u8, 256>::default();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct WithDefault<T, const V: i128>(T);
+
+impl<T, const V: i128> WithDefault<T, V>
+where
+    T: Integer,
+{
+    const IN_RANGE: () = assert!(
+        V >= T::MIN && V <= T::MAX,
+        "WithDefault value out of range for the integer type"
+    );
+
+    /// Returns `V` as a `T`.
+    #[inline]
+    pub fn default_value() -> T {
+        let () = Self::IN_RANGE;
+        T::from_i128(V)
+    }
+}
+
+impl<T, const V: i128> WithDefault<T, V> {
+    /// Wraps `value`.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+macro_rules! common_impls {
+    ([$($g:tt)*] $ty:ty => $t:ty) => {
+        impl<$($g)*> Default for $ty {
+            /// Returns the wrapped `V`.
+            #[inline]
+            fn default() -> Self {
+                Self(Self::default_value())
+            }
+        }
+
+        impl<$($g)*> Deref for $ty {
+            type Target = $t;
+
+            #[inline]
+            fn deref(&self) -> &$t {
+                &self.0
+            }
+        }
+
+        impl<$($g)*> DerefMut for $ty {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut $t {
+                &mut self.0
+            }
+        }
+
+        impl<$($g)*> From<$t> for $ty {
+            #[inline]
+            fn from(value: $t) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<$($g)*> fmt::Debug for $ty
+        where
+            $t: fmt::Debug,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl<$($g)*> fmt::Display for $ty
+        where
+            $t: fmt::Display,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        #[cfg(not(feature = "via_default_eq"))]
+        impl<$($g)*> crate::IsDefault for $ty {
+            /// Returns `true` if self is `V`.
+            #[inline]
+            fn is_default(&self) -> bool {
+                self.0 == Self::default_value()
+            }
+        }
+
+        #[cfg(not(feature = "via_default_eq"))]
+        impl<$($g)*> crate::ResetDefault for $ty {
+            /// Sets self to `V`.
+            #[inline]
+            fn reset_default(&mut self) {
+                self.0 = Self::default_value();
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<$($g)*> serde::Serialize for $ty
+        where
+            $t: serde::Serialize,
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                self.0.serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, $($g)*> serde::Deserialize<'de> for $ty
+        where
+            $t: serde::Deserialize<'de>,
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                <$t>::deserialize(deserializer).map(Self)
+            }
+        }
+    };
+}
+
+common_impls!([T: Integer, const V: i128] WithDefault<T, V> => T);
+
+macro_rules! typed_wrappers {
+    ($($name:ident($t:ty)),* $(,)?) => {
+        $(
+            #[doc = concat!("A `", stringify!($t), "` whose default value is `V`.")]
+            #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            #[repr(transparent)]
+            pub struct $name<const V: $t>($t);
+
+            impl<const V: $t> $name<V> {
+                /// Wraps `value`.
+                #[inline]
+                pub const fn new(value: $t) -> Self {
+                    Self(value)
+                }
+
+                /// Returns `V`.
+                #[inline]
+                pub const fn default_value() -> $t {
+                    V
+                }
+
+                /// Returns the value.
+                #[inline]
+                pub const fn into_inner(self) -> $t {
+                    self.0
+                }
+            }
+
+            impl<const V: $t> ConstDefault for $name<V> {
+                const DEFAULT: Self = Self(V);
+            }
+
+            impl<const V: $t> From<$name<V>> for $t {
+                #[inline]
+                fn from(value: $name<V>) -> Self {
+                    value.0
+                }
+            }
+
+            common_impls!([const V: $t] $name<V> => $t);
+        )*
+    };
+}
+
+typed_wrappers!(
+    WithDefaultBool(bool),
+    WithDefaultChar(char),
+    WithDefaultI8(i8),
+    WithDefaultI16(i16),
+    WithDefaultI32(i32),
+    WithDefaultI64(i64),
+    WithDefaultI128(i128),
+    WithDefaultIsize(isize),
+    WithDefaultU8(u8),
+    WithDefaultU16(u16),
+    WithDefaultU32(u32),
+    WithDefaultU64(u64),
+    WithDefaultU128(u128),
+    WithDefaultUsize(usize),
+);
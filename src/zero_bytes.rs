@@ -0,0 +1,94 @@
+//! Byte-wise default checks for plain-old-data types.
+//!
+//! For types whose default is all-zero bytes, comparing memory with zero
+//! is much faster than checking field by field. [`IsZeroBytes`] marks such
+//! types, and [`IsZeroBytes::is_default_bytes`] scans them a word at a time.
+
+use core::{mem::size_of, slice};
+
+/// Types whose default value is all-zero bytes and that have no padding.
+///
+/// # Safety
+///
+/// Implementors must guarantee that:
+/// - every byte of every value is initialized, so the type has no padding
+///   and, for unions, all fields cover the whole union;
+/// - a value is default if and only if all its bytes are zero.
+///
+/// The `IsZeroBytes` derive checks both for `repr(C)` and
+/// `repr(transparent)` structs and unions whose fields implement this trait.
+pub unsafe trait IsZeroBytes: Sized {
+    /// Returns `true` if all bytes of `self` are zero.
+    #[inline]
+    fn is_default_bytes(&self) -> bool {
+        // SAFETY: the trait guarantees that all bytes of `self` are
+        // initialized.
+        let bytes =
+            unsafe { slice::from_raw_parts((self as *const Self).cast::<u8>(), size_of::<Self>()) };
+        is_zero_bytes(bytes)
+    }
+}
+
+/// Returns `true` if all `bytes` are zero, scanning a word at a time.
+pub fn is_zero_bytes(bytes: &[u8]) -> bool {
+    const WORD: usize = size_of::<usize>();
+
+    let mut chunks = bytes.chunks_exact(WORD * 4);
+    let body = chunks.by_ref().all(|chunk| {
+        let word = |i: usize| {
+            let mut buf = [0u8; WORD];
+            buf.copy_from_slice(&chunk[i * WORD..(i + 1) * WORD]);
+            usize::from_ne_bytes(buf)
+        };
+        (word(0) | word(1) | word(2) | word(3)) == 0
+    });
+
+    body && chunks.remainder().iter().all(|&b| b == 0)
+}
+
+macro_rules! zero_bytes_impl {
+    ($($t:ty),*) => {
+        $(
+            // SAFETY: the type has no padding and its default is zero.
+            unsafe impl IsZeroBytes for $t {}
+        )*
+    };
+}
+
+zero_bytes_impl!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize
+);
+
+// SAFETY: arrays have no padding between elements, and the default array
+// holds default elements.
+unsafe impl<T, const N: usize> IsZeroBytes for [T; N] where T: IsZeroBytes {}
+
+/// Returns `true` if all bytes of `value` are zero.
+///
+/// This bridges `bytemuck` types, whose `Zeroable` value is all-zero bytes
+/// and which have no uninitialized bytes under `NoUninit`.
+///
+/// The bridge is this function only. `Zeroable` promises that zero is a
+/// valid value, not that it is the default, so `bytemuck` types do not
+/// implement [`IsZeroBytes`] or `IsDefault` through it.
+#[cfg(feature = "bytemuck")]
+pub fn is_zeroed<T>(value: &T) -> bool
+where
+    T: bytemuck::NoUninit + bytemuck::Zeroable,
+{
+    is_zero_bytes(bytemuck::bytes_of(value))
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "args")]
+
+use is_default::{ToArgs, ToEnv, args::ArgValue};
+use std::{ffi::OsString, path::PathBuf};
+
+#[test]
+fn arg_value() {
+    assert_eq!(true.to_arg_value(), "true");
+    assert_eq!(42u8.to_arg_value(), "42");
+    assert_eq!(1.5f64.to_arg_value(), "1.5");
+    assert_eq!("x".to_arg_value(), "x");
+    assert_eq!(PathBuf::from("/tmp").to_arg_value(), "/tmp");
+    assert_eq!(Some(3).to_arg_value(), "3");
+    assert_eq!(None::<u8>.to_arg_value(), "");
+    assert_eq!(vec![1, 2, 3].to_arg_value(), "1,2,3");
+}
+
+#[cfg(all(feature = "derive", not(feature = "via_default_eq")))]
+mod derive {
+    use super::*;
+    use is_default::IsDefault;
+
+    #[derive(Default, IsDefault, ToArgs, ToEnv)]
+    struct Net {
+        port: u16,
+        host: String,
+    }
+
+    #[derive(Default, IsDefault, ToArgs, ToEnv)]
+    #[to_env(prefix = "APP_")]
+    struct Config {
+        verbose: bool,
+        max_jobs: u32,
+        #[to_args(name = "out")]
+        #[to_env(name = "OUTPUT")]
+        output: Option<PathBuf>,
+        #[to_args(flatten)]
+        #[to_env(flatten)]
+        net: Net,
+        #[to_args(skip)]
+        #[to_env(skip)]
+        secret: String,
+    }
+
+    fn os(v: &[&str]) -> Vec<OsString> {
+        v.iter().map(OsString::from).collect()
+    }
+
+    #[test]
+    fn default_is_empty() {
+        assert!(Config::default().to_args().is_empty());
+        assert!(Config::default().to_env().is_empty());
+    }
+
+    #[test]
+    fn to_args() {
+        let config = Config {
+            verbose: true,
+            max_jobs: 4,
+            output: Some(PathBuf::from("a.txt")),
+            net: Net {
+                port: 80,
+                host: String::new(),
+            },
+            secret: "hidden".into(),
+        };
+        assert_eq!(
+            config.to_args(),
+            os(&["--verbose", "--max-jobs=4", "--out=a.txt", "--net-port=80"])
+        );
+    }
+
+    #[test]
+    fn to_env() {
+        let config = Config {
+            verbose: true,
+            net: Net {
+                port: 0,
+                host: "localhost".into(),
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            config.to_env(),
+            vec![
+                ("APP_VERBOSE".into(), "true".into()),
+                ("APP_NET_HOST".into(), "localhost".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn false_bool_is_omitted() {
+        let config = Config {
+            max_jobs: 1,
+            ..Default::default()
+        };
+        assert_eq!(config.to_args(), os(&["--max-jobs=1"]));
+    }
+
+    /// Re-spawned workers parse the emitted arguments with clap, which
+    /// uses `#[arg(...)]` on the same fields.
+    #[derive(Debug, Default, PartialEq, IsDefault, ToArgs, clap::Parser)]
+    struct Worker {
+        #[arg(long)]
+        verbose: bool,
+        #[arg(long, default_value_t)]
+        max_jobs: u32,
+        #[arg(long = "out")]
+        #[to_args(name = "out")]
+        output: Option<PathBuf>,
+        #[arg(skip)]
+        #[to_args(skip)]
+        secret: String,
+    }
+
+    #[test]
+    fn clap_round_trip() {
+        use clap::Parser;
+
+        let worker = Worker {
+            verbose: true,
+            max_jobs: 4,
+            output: Some(PathBuf::from("a.txt")),
+            secret: String::new(),
+        };
+        let args = worker.to_args();
+        assert_eq!(args, os(&["--verbose", "--max-jobs=4", "--out=a.txt"]));
+
+        let parsed = Worker::try_parse_from(std::iter::once("worker".into()).chain(args)).unwrap();
+        assert_eq!(parsed, worker);
+
+        let parsed = Worker::try_parse_from(["worker"]).unwrap();
+        assert!(parsed.to_args().is_empty());
+    }
+}
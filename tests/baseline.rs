@@ -0,0 +1,149 @@
+#![cfg(feature = "std")]
+
+use is_default::IsBaseline;
+use std::{
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, BinaryHeap, HashMap, LinkedList},
+    ffi::CString,
+    num::Wrapping,
+    rc::Rc,
+    sync::{Mutex, RwLock, atomic::AtomicU32},
+};
+
+#[test]
+fn primitives() {
+    assert!(1u8.matches_baseline(&1));
+    assert!(!1u8.matches_baseline(&2));
+    assert!("a".matches_baseline("a"));
+    assert!((1, 'x').matches_baseline(&(1, 'x')));
+    assert!(!(1, 'x').matches_baseline(&(1, 'y')));
+}
+
+#[test]
+fn options_and_collections() {
+    assert!(None::<u8>.matches_baseline(&None));
+    assert!(!Some(0).matches_baseline(&None));
+    assert!(Some(1).matches_baseline(&Some(1)));
+
+    assert!(vec![1, 2].matches_baseline(&vec![1, 2]));
+    assert!(!vec![1, 2].matches_baseline(&vec![1]));
+    assert!(!vec![1, 2].matches_baseline(&vec![1, 3]));
+
+    let map = |v: &[(u8, &'static str)]| v.iter().copied().collect::<BTreeMap<_, _>>();
+    assert!(map(&[(1, "a")]).matches_baseline(&map(&[(1, "a")])));
+    assert!(!map(&[(1, "a")]).matches_baseline(&map(&[(1, "b")])));
+    assert!(!map(&[(1, "a")]).matches_baseline(&map(&[(2, "a")])));
+
+    let map = |v: &[(u8, u8)]| v.iter().copied().collect::<HashMap<_, _>>();
+    assert!(map(&[(1, 1), (2, 2)]).matches_baseline(&map(&[(2, 2), (1, 1)])));
+    assert!(!map(&[(1, 1)]).matches_baseline(&map(&[(1, 1), (2, 2)])));
+}
+
+#[test]
+fn tuples() {
+    let t = (
+        1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8,
+    );
+    assert!(t.matches_baseline(&t));
+    assert!(!t.matches_baseline(&(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 0)));
+    assert!(().matches_baseline(&()));
+}
+
+#[test]
+fn std_types() {
+    assert!(Cell::new(1).matches_baseline(&Cell::new(1)));
+    assert!(!RefCell::new(1).matches_baseline(&RefCell::new(2)));
+    assert!(Mutex::new(vec![1]).matches_baseline(&Mutex::new(vec![1])));
+    assert!(!RwLock::new(1).matches_baseline(&RwLock::new(2)));
+    assert!(AtomicU32::new(3).matches_baseline(&AtomicU32::new(3)));
+    assert!(Wrapping(1).matches_baseline(&Wrapping(1)));
+    assert!(
+        CString::new("a")
+            .unwrap()
+            .matches_baseline(&CString::new("a").unwrap())
+    );
+
+    let list = |v: &[u8]| v.iter().copied().collect::<LinkedList<_>>();
+    assert!(list(&[1, 2]).matches_baseline(&list(&[1, 2])));
+    assert!(!list(&[1, 2]).matches_baseline(&list(&[2, 1])));
+
+    let heap = |v: &[u8]| v.iter().copied().collect::<BinaryHeap<_>>();
+    assert!(heap(&[1, 3, 2]).matches_baseline(&heap(&[2, 1, 3])));
+    assert!(!heap(&[1, 2]).matches_baseline(&heap(&[1, 1])));
+
+    let rc = Rc::new(1);
+    assert!(Rc::downgrade(&rc).matches_baseline(&Rc::downgrade(&Rc::new(1))));
+    assert!(!Rc::downgrade(&rc).matches_baseline(&std::rc::Weak::new()));
+}
+
+#[test]
+fn locked_values() {
+    let cell = RefCell::new(1);
+    let _guard = cell.borrow_mut();
+    assert!(cell.matches_baseline(&cell));
+    assert!(!cell.matches_baseline(&RefCell::new(1)));
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use is_default::IsBaseline;
+
+    #[derive(Clone, IsBaseline)]
+    struct Limits {
+        memory: u64,
+        files: Option<u32>,
+    }
+
+    #[derive(Clone, IsBaseline)]
+    struct Profile {
+        name: String,
+        limits: Limits,
+        tags: Vec<String>,
+        mode: Mode,
+    }
+
+    #[derive(Clone, IsBaseline)]
+    enum Mode {
+        Off,
+        Level(u8),
+        Custom { level: u8 },
+    }
+
+    #[derive(IsBaseline)]
+    struct Unit;
+
+    fn prod() -> Profile {
+        Profile {
+            name: "prod".into(),
+            limits: Limits {
+                memory: 1 << 30,
+                files: Some(1024),
+            },
+            tags: vec!["a".into()],
+            mode: Mode::Level(2),
+        }
+    }
+
+    #[test]
+    fn structs() {
+        let baseline = prod();
+        let mut p = prod();
+        assert!(p.matches_baseline(&baseline));
+
+        p.limits.files = None;
+        assert!(!p.matches_baseline(&baseline));
+        assert!(!p.limits.matches_baseline(&baseline.limits));
+        assert!(p.tags.matches_baseline(&baseline.tags));
+
+        assert!(Unit.matches_baseline(&Unit));
+    }
+
+    #[test]
+    fn enums() {
+        assert!(Mode::Off.matches_baseline(&Mode::Off));
+        assert!(Mode::Level(1).matches_baseline(&Mode::Level(1)));
+        assert!(!Mode::Level(1).matches_baseline(&Mode::Level(2)));
+        assert!(!Mode::Level(1).matches_baseline(&Mode::Custom { level: 1 }));
+        assert!(Mode::Custom { level: 1 }.matches_baseline(&Mode::Custom { level: 1 }));
+    }
+}
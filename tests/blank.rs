@@ -0,0 +1,157 @@
+use is_default::{IsBlank, Trim};
+
+#[test]
+fn primitives() {
+    assert!(0u8.is_blank());
+    assert!(!1u8.is_blank());
+    assert!((-0.0f32).is_blank());
+    assert!(!f64::NAN.is_blank());
+    assert!(' '.is_blank());
+    assert!(!'a'.is_blank());
+    assert!("".is_blank());
+    assert!(" \t\n".is_blank());
+    assert!(!" a ".is_blank());
+}
+
+#[test]
+fn options_and_arrays() {
+    assert!(None::<u8>.is_blank());
+    assert!(Some(0).is_blank());
+    assert!(Some(Some("  ")).is_blank());
+    assert!(!Some(1).is_blank());
+    assert!([0, 0].is_blank());
+    assert!(![0, 1].is_blank());
+    assert!((0, "").is_blank());
+    assert!(().is_blank());
+    assert!((0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, " ").is_blank());
+    assert!(!(0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, "x").is_blank());
+}
+
+#[test]
+fn trim() {
+    let nbsp = "\u{a0} ";
+    assert!(nbsp.is_blank());
+    assert!(nbsp.is_blank_with(Trim::Unicode));
+    assert!(!nbsp.is_blank_with(Trim::Ascii));
+    assert!(" \t".is_blank_with(Trim::Ascii));
+    assert!(!" ".is_blank_with(Trim::Never));
+    assert!("".is_blank_with(Trim::Never));
+    assert!(!'\u{a0}'.is_blank_with(Trim::Ascii));
+    assert!('\0'.is_blank_with(Trim::Never));
+    assert!(!Some((0, [nbsp])).is_blank_with(Trim::Ascii));
+    assert!(Some((0, [" "])).is_blank_with(Trim::Ascii));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn collections() {
+    use std::collections::{BTreeMap, HashMap, HashSet};
+
+    assert!(vec![0, 0].is_blank());
+    assert!(!vec![0, 1].is_blank());
+    assert!(String::from(" ").is_blank());
+    assert!(vec![String::new(), " ".into()].is_blank());
+    assert!(HashSet::from([0]).is_blank());
+
+    let mut m: BTreeMap<&str, Option<String>> = [("a", None), ("b", Some(" ".into()))].into();
+    assert!(m.is_blank());
+    m.insert("c", Some("x".into()));
+    assert!(!m.is_blank());
+
+    assert!(HashMap::from([("k", 0)]).is_blank());
+
+    assert!(!vec![String::from("\u{a0}")].is_blank_with(Trim::Ascii));
+    assert!(!BTreeMap::from([("k", " ")]).is_blank_with(Trim::Never));
+}
+
+#[cfg(all(feature = "derive", feature = "std", not(feature = "via_default_eq")))]
+mod derive {
+    use is_default::{IsBlank, IsDefault, Trim};
+
+    #[derive(IsDefault, IsBlank)]
+    struct Address {
+        street: String,
+        #[is_blank(strict)]
+        code: String,
+    }
+
+    #[derive(IsBlank)]
+    struct Form {
+        name: Option<String>,
+        tags: Vec<String>,
+        address: Address,
+        kind: Kind,
+    }
+
+    #[derive(IsBlank)]
+    enum Kind {
+        #[default]
+        Unset,
+        Person,
+    }
+
+    #[derive(IsDefault, IsBlank)]
+    #[is_blank(strict)]
+    struct Strict(String, Option<u8>);
+
+    #[derive(IsBlank)]
+    #[is_blank(trim = "ascii")]
+    struct Ascii {
+        name: String,
+        address: Address,
+        #[is_blank(trim = "never")]
+        note: Option<String>,
+    }
+
+    #[test]
+    fn structs() {
+        let mut form = Form {
+            name: Some("  ".into()),
+            tags: vec![String::new()],
+            address: Address {
+                street: " ".into(),
+                code: String::new(),
+            },
+            kind: Kind::Unset,
+        };
+        assert!(form.is_blank());
+
+        form.address.code = " ".into();
+        assert!(!form.is_blank());
+
+        form.address.code.clear();
+        form.kind = Kind::Person;
+        assert!(!form.is_blank());
+
+        assert!(Strict(String::new(), None).is_blank());
+        assert!(!Strict(" ".into(), None).is_blank());
+        assert!(!Strict(String::new(), Some(0)).is_blank());
+    }
+
+    #[test]
+    fn trim() {
+        let mut ascii = Ascii {
+            name: " ".into(),
+            address: Address {
+                street: "\u{a0}".into(),
+                code: String::new(),
+            },
+            note: None,
+        };
+        assert!(!ascii.is_blank());
+
+        ascii.address.street = "\t".into();
+        assert!(ascii.is_blank());
+        assert!(ascii.is_blank_with(Trim::Unicode));
+
+        ascii.note = Some(" ".into());
+        assert!(!ascii.is_blank());
+
+        let address = Address {
+            street: "\u{a0}".into(),
+            code: String::new(),
+        };
+        assert!(address.is_blank());
+        assert!(!address.is_blank_with(Trim::Ascii));
+    }
+}
@@ -0,0 +1,97 @@
+use is_default::{ConstDefault, is_default_const};
+
+const fn is_unset(port: u16, host: &str, ratio: f32, flags: [bool; 2]) -> bool {
+    is_default_const!(port)
+        && is_default_const!(host)
+        && is_default_const!(ratio)
+        && is_default_const!(flags)
+}
+
+const _: () = assert!(is_unset(0, "", 0.0, [false; 2]));
+const _: () = assert!(!is_unset(0, "", 0.0, [false, true]));
+
+#[test]
+fn macro_checks() {
+    assert!(is_default_const!(0u8));
+    assert!(!is_default_const!(1i64));
+    assert!(is_default_const!(-0.0f64));
+    assert!(is_default_const!('\x00'));
+    assert!(!is_default_const!('a'));
+    assert!(is_default_const!(None::<String>));
+    assert!(!is_default_const!(Some(0)));
+    assert!(is_default_const!([0u32; 4]));
+    assert!(!is_default_const!([0, 0, 1u32]));
+    assert!(is_default_const!(()));
+}
+
+#[test]
+fn primitives() {
+    assert_eq!(u8::DEFAULT, 0);
+    assert_eq!(bool::DEFAULT, bool::default());
+    assert_eq!(<&str>::DEFAULT, "");
+    assert_eq!(<[i32; 3]>::DEFAULT, [0; 3]);
+    assert_eq!(<(u8, char)>::DEFAULT, (0, '\x00'));
+    assert_eq!(Option::<u8>::DEFAULT, None);
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use is_default::ConstDefault;
+
+    #[derive(Debug, PartialEq, ConstDefault)]
+    struct Entry {
+        id: u32,
+        name: &'static str,
+        weights: [f32; 2],
+        parent: Option<u32>,
+    }
+
+    #[derive(Debug, PartialEq, ConstDefault)]
+    struct Table {
+        first: Entry,
+        len: usize,
+    }
+
+    #[derive(Debug, PartialEq, ConstDefault)]
+    struct Tuple(u8, bool);
+
+    #[derive(Debug, PartialEq, ConstDefault)]
+    enum Mode {
+        Off,
+        #[default]
+        Auto,
+    }
+
+    const ENTRIES: [Entry; 2] = [
+        Entry::DEFAULT,
+        Entry {
+            id: 1,
+            ..Entry::DEFAULT
+        },
+    ];
+
+    const _: () = assert!(ENTRIES[0].is_default_const());
+    const _: () = assert!(!ENTRIES[1].is_default_const());
+    const _: () = assert!(Mode::DEFAULT.is_default_const());
+
+    #[test]
+    fn derived() {
+        assert_eq!(
+            Table::DEFAULT,
+            Table {
+                first: Entry {
+                    id: 0,
+                    name: "",
+                    weights: [0.0; 2],
+                    parent: None,
+                },
+                len: 0,
+            }
+        );
+        assert_eq!(Tuple::DEFAULT, Tuple(0, false));
+        assert!(Tuple(0, false).is_default_const());
+        assert!(!Tuple(0, true).is_default_const());
+        assert_eq!(Mode::DEFAULT, Mode::Auto);
+        assert!(!Mode::Off.is_default_const());
+    }
+}
@@ -0,0 +1,74 @@
+use is_default::{DefaultAsNone, IsDefault};
+use std::mem::size_of;
+
+#[test]
+fn layout() {
+    assert_eq!(size_of::<DefaultAsNone<u32>>(), size_of::<u32>());
+    assert_eq!(size_of::<DefaultAsNone<u64>>(), size_of::<u64>());
+}
+
+#[test]
+fn option_api() {
+    let mut v = DefaultAsNone::new(0u32);
+    assert!(v.is_none());
+    assert_eq!(v.get(), None);
+    assert_eq!(v, DefaultAsNone::none());
+
+    assert_eq!(v.replace(7), None);
+    assert!(v.is_some());
+    assert_eq!(v.get(), Some(&7));
+    assert_eq!(v.map(|x| x * 2), Some(14));
+    assert_eq!(v.unwrap_or(1), 7);
+
+    assert_eq!(v.take(), Some(7));
+    assert!(v.is_none());
+    assert_eq!(v.unwrap_or(1), 1);
+    assert_eq!(v.into_inner(), 0);
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(DefaultAsNone::from(Some(3u8)).into_option(), Some(3));
+    assert_eq!(DefaultAsNone::from(None::<u8>).into_option(), None);
+    assert_eq!(Option::from(DefaultAsNone::from(Some(0u8))), None::<u8>);
+    assert_eq!(format!("{:?}", DefaultAsNone::new(5u8)), "Some(5)");
+    assert_eq!(format!("{:?}", DefaultAsNone::new(0u8)), "None");
+}
+
+#[test]
+fn is_default() {
+    assert!(DefaultAsNone::new(0i64).is_default());
+    assert!(!DefaultAsNone::new(-1i64).is_default());
+}
+
+#[cfg(all(feature = "serde", feature = "std"))]
+#[test]
+fn serde() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Row {
+        id: u32,
+        #[serde(default, skip_serializing_if = "is_default::is_default")]
+        parent: DefaultAsNone<u32>,
+    }
+
+    let row = Row {
+        id: 1,
+        parent: DefaultAsNone::none(),
+    };
+    let json = serde_json::to_string(&row).unwrap();
+    assert_eq!(json, r#"{"id":1}"#);
+    assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+
+    let row = Row {
+        id: 2,
+        parent: DefaultAsNone::new(1),
+    };
+    let json = serde_json::to_string(&row).unwrap();
+    assert_eq!(json, r#"{"id":2,"parent":1}"#);
+    assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+
+    let row: Row = serde_json::from_str(r#"{"id":3,"parent":null}"#).unwrap();
+    assert!(row.parent.is_none());
+}
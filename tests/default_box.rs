@@ -0,0 +1,127 @@
+#![cfg(feature = "std")]
+
+use is_default::DefaultBox;
+use std::mem::size_of;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Large {
+    name: String,
+    limits: [u64; 16],
+}
+
+#[cfg(not(feature = "via_default_eq"))]
+impl is_default::IsDefault for Large {
+    fn is_default(&self) -> bool {
+        self.name.is_empty() && self.limits == [0; 16]
+    }
+}
+
+#[test]
+fn layout() {
+    assert_eq!(size_of::<DefaultBox<Large>>(), size_of::<Box<Large>>());
+}
+
+#[test]
+fn read_does_not_allocate() {
+    let v = DefaultBox::<Large>::default();
+    assert_eq!(v.get(), None);
+    assert_eq!(*v.get_or_default(), Large::default());
+    assert!(!v.is_allocated());
+}
+
+#[test]
+fn interior_mutability_is_not_shared() {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    let mut a = DefaultBox::<AtomicU64>::default();
+    a.get_mut().fetch_add(5, Ordering::Relaxed);
+    let b = DefaultBox::<AtomicU64>::default();
+    assert!(b.get().is_none());
+    assert_eq!(b.into_inner().load(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn write_allocates() {
+    let mut v = DefaultBox::<Large>::default();
+    v.get_mut().name.push('x');
+    assert!(v.is_allocated());
+    assert_eq!(v.get().unwrap().name, "x");
+
+    v.get_mut().name.clear();
+    assert!(v.is_allocated());
+    v.shrink();
+    assert!(!v.is_allocated());
+}
+
+#[test]
+fn set() {
+    let mut v = DefaultBox::new(0u64);
+    assert!(!v.is_allocated());
+    v.set(3);
+    assert!(v.is_allocated());
+    assert_eq!(v.get(), Some(&3));
+    v.set(0);
+    assert!(!v.is_allocated());
+    assert_eq!(v.clone().into_inner(), 0);
+    assert_eq!(DefaultBox::from(5u64).into_inner(), 5);
+}
+
+#[test]
+fn eq_and_debug() {
+    let mut a = DefaultBox::<u8>::default();
+    *a.get_mut() = 0;
+    assert!(a.is_allocated());
+    assert_eq!(a, DefaultBox::default());
+    assert_ne!(DefaultBox::new(1u8), DefaultBox::default());
+    assert_eq!(format!("{:?}", DefaultBox::new(7u8)), "7");
+    assert_eq!(format!("{:?}", DefaultBox::<u8>::default()), "0");
+
+    // Non-`Sync` types work as well.
+    let cell = DefaultBox::<std::cell::Cell<u8>>::default();
+    assert_eq!(cell, DefaultBox::default());
+}
+
+#[cfg(not(feature = "via_default_eq"))]
+#[test]
+fn is_default() {
+    use is_default::{IsDefault, ResetDefault};
+
+    let mut v = DefaultBox::<Large>::default();
+    assert!(v.is_default());
+    v.get_mut().limits[0] = 1;
+    assert!(!v.is_default());
+    v.get_mut().limits[0] = 0;
+    assert!(v.is_default());
+    v.get_mut().limits[0] = 1;
+    v.reset_default();
+    assert!(!v.is_allocated());
+}
+
+#[cfg(all(feature = "derive", not(feature = "via_default_eq")))]
+#[test]
+fn derive() {
+    use is_default::IsDefault;
+
+    #[derive(Default, IsDefault)]
+    struct Entity {
+        id: u32,
+        extra: DefaultBox<Large>,
+    }
+
+    let mut entity = Entity::default();
+    assert!(entity.is_default());
+    entity.extra.get_mut().name.push('x');
+    assert!(!entity.is_default());
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn serde() {
+    let v: DefaultBox<u32> = serde_json::from_str("0").unwrap();
+    assert!(!v.is_allocated());
+    assert_eq!(serde_json::to_string(&v).unwrap(), "0");
+
+    let v: DefaultBox<u32> = serde_json::from_str("4").unwrap();
+    assert!(v.is_allocated());
+    assert_eq!(serde_json::to_string(&v).unwrap(), "4");
+}
@@ -1,6 +1,8 @@
+#![allow(clippy::unnecessary_mut_passed)]
+
 #[cfg(all(feature = "derive", not(feature = "via_default_eq")))]
 mod derive {
-    use is_default::IsDefault;
+    use is_default::{IsDefault, NonDefaultDebug, ResetDefault};
 
     #[test]
     fn tuple_ref_structs() {
@@ -18,4 +20,120 @@ mod derive {
         assert!(!Ref(&non_zero).is_default());
         assert!(!RefMut(&mut non_zero).is_default());
     }
+
+    #[test]
+    fn reset_default_structs() {
+        #[derive(IsDefault, ResetDefault)]
+        struct Unit;
+
+        #[derive(IsDefault, ResetDefault)]
+        struct Wrapper(u8, char);
+
+        #[derive(IsDefault, ResetDefault)]
+        struct Config {
+            name: [u8; 4],
+            retries: u8,
+            inner: Wrapper,
+        }
+
+        let mut unit = Unit;
+        unit.reset_default();
+        assert!(unit.is_default());
+        assert!(!unit.reset_field("x"));
+
+        let mut config = Config {
+            name: *b"name",
+            retries: 3,
+            inner: Wrapper(1, 'x'),
+        };
+
+        assert!(config.reset_field("retries"));
+        assert_eq!(config.retries, 0);
+        assert!(!config.is_default());
+        assert!(config.inner.reset_field("1"));
+        assert_eq!(config.inner.1, '\x00');
+        assert!(!config.reset_field("unknown"));
+
+        config.reset_default();
+        assert!(config.is_default());
+    }
+
+    #[test]
+    fn reset_default_enums() {
+        #[derive(IsDefault, ResetDefault)]
+        enum A {
+            X,
+            #[is_default]
+            Y,
+        }
+
+        #[derive(Default, PartialEq, IsDefault, ResetDefault)]
+        enum B {
+            X(u8),
+            #[default]
+            Y,
+        }
+
+        let mut a = A::X;
+        a.reset_default();
+        assert!(a.is_default());
+
+        let mut b = B::X(1);
+        assert!(matches!(b.take_if_non_default(), Some(B::X(1))));
+        assert!(b.is_default());
+        assert!(b.take_if_non_default().is_none());
+    }
+
+    #[test]
+    fn non_default_debug() {
+        #[derive(IsDefault, NonDefaultDebug)]
+        struct Unit;
+
+        #[derive(IsDefault, NonDefaultDebug)]
+        struct Wrapper(u8, bool);
+
+        #[derive(IsDefault, NonDefaultDebug)]
+        struct Config {
+            retries: u8,
+            ratio: f32,
+            inner: Wrapper,
+        }
+
+        #[derive(NonDefaultDebug)]
+        enum E {
+            A,
+            B(u8, u8),
+            C { x: i8, y: i8 },
+        }
+
+        assert_eq!(format!("{:?}", Unit), "Unit");
+        assert_eq!(format!("{:?}", Wrapper(0, false)), "Wrapper(..)");
+        assert_eq!(format!("{:?}", Wrapper(1, true)), "Wrapper(1, true)");
+        assert_eq!(format!("{:?}", Wrapper(0, true)), "Wrapper(true, ..)");
+
+        let config = Config {
+            retries: 0,
+            ratio: 0.0,
+            inner: Wrapper(0, false),
+        };
+        assert_eq!(format!("{:?}", config), "Config { .. }");
+
+        let config = Config {
+            retries: 3,
+            ratio: 0.0,
+            inner: Wrapper(1, false),
+        };
+        assert_eq!(
+            format!("{:?}", config),
+            "Config { retries: 3, inner: Wrapper(1, ..), .. }"
+        );
+        assert_eq!(
+            format!("{:#?}", config),
+            "Config {\n    retries: 3,\n    inner: Wrapper(\n        1,\n        ..\n    ),\n    ..\n}"
+        );
+
+        assert_eq!(format!("{:?}", E::A), "A");
+        assert_eq!(format!("{:?}", E::B(0, 1)), "B(1, ..)");
+        assert_eq!(format!("{:?}", E::C { x: 1, y: 2 }), "C { x: 1, y: 2 }");
+    }
 }
@@ -0,0 +1,102 @@
+use is_default::{IsDefaultExt, IsDefaultIterExt, IsDefaultSliceExt};
+
+#[test]
+fn value() {
+    assert!(1u8.is_non_default());
+    assert!(!0u8.is_non_default());
+
+    assert_eq!(0u8.non_default(), None);
+    assert_eq!(5u8.non_default(), Some(5));
+    assert_eq!('\x00'.non_default_ref(), None);
+    assert_eq!('a'.non_default_ref(), Some(&'a'));
+
+    assert_eq!(0u16.or_if_default(8080), 8080);
+    assert_eq!(80u16.or_if_default(8080), 80);
+    assert_eq!(0.0f32.or_else_if_default(|| 1.5), 1.5);
+    assert_eq!(2.0f32.or_else_if_default(|| unreachable!()), 2.0);
+}
+
+#[cfg(not(feature = "via_default_eq"))]
+#[test]
+fn unsized_value() {
+    assert_eq!("".non_default_ref(), None);
+    assert_eq!("x".non_default_ref(), Some("x"));
+    assert!([1u8, 0][..].is_non_default());
+}
+
+#[test]
+fn iter() {
+    let v = [0u8, 1, 0, 2, 0];
+    assert_eq!(
+        v.into_iter().filter_non_default().collect::<Vec<_>>(),
+        [1, 2]
+    );
+    assert!(!v.into_iter().all_default());
+    assert!([0u8; 3].into_iter().all_default());
+    assert_eq!(v.into_iter().count_non_default(), 2);
+    assert_eq!(v.into_iter().position_non_default(), Some(1));
+    assert_eq!([0u8; 3].into_iter().position_non_default(), None);
+}
+
+#[test]
+fn slice() {
+    let mut v = vec![0u32; 1000];
+    assert!(v.all_default());
+    assert_eq!(v.count_non_default(), 0);
+    assert_eq!(v.position_non_default(), None);
+    assert!(v.trim_trailing_defaults().is_empty());
+
+    v[300] = 1;
+    v[700] = 2;
+    assert!(!v.all_default());
+    assert_eq!(v.count_non_default(), 2);
+    assert_eq!(v.position_non_default(), Some(300));
+    assert_eq!(v.trim_trailing_defaults().len(), 701);
+
+    v[999] = 3;
+    assert_eq!(v.trim_trailing_defaults().len(), 1000);
+
+    let empty: &[f64] = &[];
+    assert!(empty.all_default());
+    assert!(empty.trim_trailing_defaults().is_empty());
+}
+
+#[test]
+fn trim_matches_loop() {
+    for len in [0, 1, 255, 256, 257, 600] {
+        for last in 0..len.min(300) {
+            let mut v = vec![0i16; len];
+            v[last] = -1;
+            assert_eq!(v.trim_trailing_defaults().len(), last + 1, "{len} {last}");
+            assert_eq!(v.position_non_default(), Some(last));
+        }
+    }
+}
+
+#[test]
+fn split_on_default() {
+    let records = b"ab\0cd\0\0e";
+    let parts: Vec<&[u8]> = records.split_on_default().collect();
+    assert_eq!(parts, [&b"ab"[..], b"cd", b"", b"e"]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn vec() {
+    use is_default::IsDefaultVecExt;
+
+    let mut v = vec![
+        String::new(),
+        "a".into(),
+        String::new(),
+        "b".into(),
+        String::new(),
+    ];
+    let mut trimmed = v.clone();
+
+    v.retain_non_default();
+    assert_eq!(v, ["a", "b"]);
+
+    trimmed.truncate_trailing_defaults();
+    assert_eq!(trimmed, ["", "a", "", "b"]);
+}
@@ -0,0 +1,81 @@
+#![cfg(all(feature = "derive", not(feature = "via_default_eq")))]
+
+use is_default::{IsDefault, IsDefaultFields};
+
+#[derive(Default, IsDefault, IsDefaultFields)]
+struct Tls {
+    enabled: bool,
+    port: u16,
+}
+
+#[derive(Default, IsDefault, IsDefaultFields)]
+struct Server {
+    #[is_default(group = "network")]
+    port: u16,
+    #[is_default(group = "network", nested)]
+    tls: Tls,
+    #[is_default(group = "storage")]
+    cache_size: u32,
+    #[is_default(group = "storage", group = "advanced")]
+    r#sync: bool,
+}
+
+#[derive(Default, IsDefault, IsDefaultFields)]
+struct Config {
+    #[is_default(nested)]
+    server: Server,
+    workers: u8,
+}
+
+#[derive(Default, IsDefault, IsDefaultFields)]
+struct Tuple(u8, #[is_default(group = "last")] u8);
+
+#[test]
+fn is_group_default() {
+    let mut s = Server::default();
+    assert!(s.is_group_default("network"));
+    assert!(s.is_group_default("storage"));
+
+    s.tls.port = 443;
+    assert!(!s.is_group_default("network"));
+    assert!(s.is_group_default("storage"));
+
+    s.sync = true;
+    assert!(!s.is_group_default("storage"));
+    assert!(!s.is_group_default("advanced"));
+    assert!(s.is_group_default("unknown"));
+
+    assert!(!Tuple(0, 1).is_group_default("last"));
+    assert!(Tuple(1, 0).is_group_default("last"));
+}
+
+#[test]
+fn is_default_except() {
+    let s = Server {
+        port: 80,
+        ..Default::default()
+    };
+    assert!(!s.is_default_except(&[]));
+    assert!(s.is_default_except(&["port"]));
+    assert!(!s.is_default_except(&["tls"]));
+    assert!(Tuple(1, 0).is_default_except(&["0"]));
+}
+
+#[test]
+fn is_default_at() {
+    let mut c = Config::default();
+    c.server.tls.enabled = true;
+
+    assert_eq!(c.is_default_at("workers"), Some(true));
+    assert_eq!(c.is_default_at("server"), Some(false));
+    assert_eq!(c.is_default_at("server.port"), Some(true));
+    assert_eq!(c.is_default_at("server.tls"), Some(false));
+    assert_eq!(c.is_default_at("server.tls.enabled"), Some(false));
+    assert_eq!(c.is_default_at("server.tls.port"), Some(true));
+    assert_eq!(c.is_default_at("server.sync"), Some(true));
+
+    assert_eq!(c.is_default_at("missing"), None);
+    assert_eq!(c.is_default_at("server.tls.missing"), None);
+    assert_eq!(c.is_default_at("workers.inner"), None);
+    assert_eq!(c.is_default_at(""), None);
+}
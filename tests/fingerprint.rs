@@ -0,0 +1,153 @@
+use is_default::{DefaultInsensitiveHash, fingerprint::Fnv1a64};
+use std::hash::Hasher;
+
+#[test]
+fn fnv1a64() {
+    let hash = |bytes: &[u8]| {
+        let mut h = Fnv1a64::new();
+        h.write(bytes);
+        h.finish()
+    };
+    assert_eq!(hash(b""), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(hash(b"a"), 0xaf63_dc4c_8601_ec8c);
+    assert_eq!(hash(b"foobar"), 0x8594_4171_f739_67e8);
+}
+
+#[test]
+fn platform_independent_widths() {
+    assert_eq!(7usize.fingerprint_u64(), 7u64.fingerprint_u64());
+    assert_eq!((-7isize).fingerprint_u64(), (-7i64).fingerprint_u64());
+    assert_ne!(7u32.fingerprint_u64(), 7u64.fingerprint_u64());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn containers() {
+    assert_ne!(None::<u8>.fingerprint_u64(), Some(0u8).fingerprint_u64());
+    assert_ne!(
+        vec!["ab", "c"].fingerprint_u64(),
+        vec!["a", "bc"].fingerprint_u64()
+    );
+    assert_eq!([1u8, 2].fingerprint_u64(), vec![1u8, 2].fingerprint_u64());
+    assert_eq!(String::from("x").fingerprint_u64(), "x".fingerprint_u64());
+}
+
+#[cfg(all(feature = "derive", feature = "std", not(feature = "via_default_eq")))]
+mod derive {
+    use is_default::{DefaultInsensitiveHash, IsDefault};
+
+    #[derive(Default, IsDefault, DefaultInsensitiveHash)]
+    struct V1 {
+        name: String,
+        jobs: u32,
+    }
+
+    #[derive(Default, IsDefault, DefaultInsensitiveHash)]
+    struct V2 {
+        jobs: u32,
+        name: String,
+        added: Option<u64>,
+        #[fingerprint(skip)]
+        cache_dir: String,
+    }
+
+    #[derive(Default, IsDefault, DefaultInsensitiveHash)]
+    struct Renamed {
+        #[fingerprint(name = "name")]
+        title: String,
+        jobs: u32,
+    }
+
+    #[derive(Default, IsDefault, DefaultInsensitiveHash)]
+    struct Tuple(u8, u8);
+
+    #[derive(Default, IsDefault, DefaultInsensitiveHash)]
+    enum Mode {
+        #[default]
+        Off,
+        On {
+            level: u8,
+        },
+        Other(u8),
+    }
+
+    #[test]
+    fn default_fields_are_ignored() {
+        let v1 = V1 {
+            name: "x".into(),
+            jobs: 4,
+        };
+        let v2 = V2 {
+            jobs: 4,
+            name: "x".into(),
+            added: None,
+            cache_dir: "/tmp".into(),
+        };
+        assert_eq!(v1.fingerprint_u64(), v2.fingerprint_u64());
+        assert_eq!(
+            V1::default().fingerprint_u64(),
+            V2::default().fingerprint_u64()
+        );
+
+        let v2 = V2 {
+            added: Some(0),
+            ..v2
+        };
+        assert_ne!(v1.fingerprint_u64(), v2.fingerprint_u64());
+    }
+
+    #[test]
+    fn renamed_fields() {
+        let v1 = V1 {
+            name: "x".into(),
+            jobs: 1,
+        };
+        let renamed = Renamed {
+            title: "x".into(),
+            jobs: 1,
+        };
+        assert_eq!(v1.fingerprint_u64(), renamed.fingerprint_u64());
+    }
+
+    #[test]
+    fn fields_are_tagged() {
+        assert_ne!(Tuple(1, 0).fingerprint_u64(), Tuple(0, 1).fingerprint_u64());
+    }
+
+    #[derive(Default, IsDefault, DefaultInsensitiveHash)]
+    struct Inner {
+        b: u8,
+        c: u8,
+    }
+
+    #[derive(Default, IsDefault, DefaultInsensitiveHash)]
+    struct Cfg {
+        a: Inner,
+        c: u8,
+    }
+
+    #[test]
+    fn nested_fields_do_not_run_together() {
+        let lhs = Cfg {
+            a: Inner { b: 1, c: 2 },
+            c: 0,
+        };
+        let rhs = Cfg {
+            a: Inner { b: 1, c: 0 },
+            c: 2,
+        };
+        assert_ne!(lhs.fingerprint_u64(), rhs.fingerprint_u64());
+    }
+
+    #[test]
+    fn enums() {
+        assert_ne!(
+            Mode::Off.fingerprint_u64(),
+            Mode::On { level: 0 }.fingerprint_u64()
+        );
+        assert_ne!(
+            Mode::On { level: 1 }.fingerprint_u64(),
+            Mode::Other(1).fingerprint_u64()
+        );
+    }
+}
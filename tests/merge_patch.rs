@@ -0,0 +1,294 @@
+#![cfg(all(
+    feature = "derive",
+    feature = "serde",
+    feature = "serde_json",
+    not(feature = "via_default_eq")
+))]
+
+use is_default::{
+    IsBaseline, IsDefault, apply_merge_patch, mask_baseline, to_merge_patch, to_merge_patch_from,
+};
+use proptest::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Default, Clone, PartialEq, IsDefault, Serialize, Deserialize)]
+struct Tls {
+    enabled: bool,
+    cert: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, IsDefault, Serialize, Deserialize)]
+struct Server {
+    host: String,
+    port: u16,
+    tls: Tls,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, IsDefault, Serialize, Deserialize)]
+struct Config {
+    server: Server,
+    retries: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    proxy: Option<String>,
+    tags: Vec<String>,
+    limits: BTreeMap<String, u32>,
+    name: Option<String>,
+}
+
+#[test]
+fn default_is_empty_patch() {
+    assert_eq!(to_merge_patch(&Config::default()).unwrap(), json!({}));
+}
+
+#[test]
+fn only_non_default_fields() {
+    let mut config = Config::default();
+    config.server.tls.enabled = true;
+    config.retries = 3;
+
+    let patch = to_merge_patch(&config).unwrap();
+    assert_eq!(
+        patch,
+        json!({ "server": { "tls": { "enabled": true } }, "retries": 3 })
+    );
+}
+
+#[test]
+fn round_trip() {
+    let config = Config {
+        server: Server {
+            host: "example.com".into(),
+            port: 0,
+            tls: Tls {
+                enabled: false,
+                cert: "cert.pem".into(),
+            },
+        },
+        retries: 0,
+        proxy: Some("proxy".into()),
+        tags: vec!["a".into(), String::new()],
+        limits: BTreeMap::from([("a".into(), 1)]),
+        name: Some(String::new()),
+    };
+
+    let patch = to_merge_patch(&config).unwrap();
+    let mut patched = Config::default();
+    apply_merge_patch(&mut patched, patch).unwrap();
+    assert_eq!(patched, config);
+}
+
+#[test]
+fn apply_removes_with_null() {
+    let mut config = Config {
+        proxy: Some("proxy".into()),
+        retries: 1,
+        ..Config::default()
+    };
+    apply_merge_patch(&mut config, json!({ "proxy": null, "retries": 2 })).unwrap();
+    assert_eq!(config.proxy, None);
+    assert_eq!(config.retries, 2);
+}
+
+#[test]
+fn apply_invalid_patch() {
+    let mut config = Config {
+        retries: 1,
+        ..Config::default()
+    };
+    assert!(apply_merge_patch(&mut config, json!({ "retries": "x" })).is_err());
+    assert_eq!(config.retries, 1);
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct Logging {
+    level: Option<u8>,
+    file: Option<String>,
+}
+
+impl Default for Logging {
+    fn default() -> Self {
+        Self {
+            level: Some(3),
+            file: None,
+        }
+    }
+}
+
+impl IsDefault for Logging {
+    fn is_default(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+#[test]
+fn serde_default_container() {
+    let logging = Logging {
+        level: Some(5),
+        file: Some("log.txt".into()),
+    };
+    let patch = to_merge_patch(&logging).unwrap();
+    assert_eq!(patch, json!({ "level": 5, "file": "log.txt" }));
+
+    let mut patched = Logging::default();
+    apply_merge_patch(&mut patched, patch).unwrap();
+    assert_eq!(patched, logging);
+}
+
+#[test]
+fn member_changed_to_null_is_an_error() {
+    let logging = Logging {
+        level: None,
+        file: None,
+    };
+    let err = to_merge_patch(&logging).unwrap_err();
+    assert!(err.to_string().contains("`level`"));
+}
+
+#[derive(Debug, Default, Clone, PartialEq, IsDefault, Serialize, Deserialize)]
+struct Maps {
+    limits: BTreeMap<String, Option<u8>>,
+    nested: BTreeMap<String, BTreeMap<String, Option<u8>>>,
+    list: Vec<Option<u8>>,
+    tls: Option<Tls>,
+}
+
+#[test]
+fn added_null_member_is_an_error() {
+    let maps = Maps {
+        limits: BTreeMap::from([("a".into(), None)]),
+        ..Maps::default()
+    };
+    let err = to_merge_patch(&maps).unwrap_err();
+    assert!(err.to_string().contains("`a`"));
+
+    let maps = Maps {
+        nested: BTreeMap::from([("a".into(), BTreeMap::from([("b".into(), None)]))]),
+        ..Maps::default()
+    };
+    let err = to_merge_patch(&maps).unwrap_err();
+    assert!(err.to_string().contains("`b`"));
+}
+
+#[test]
+fn null_in_list_is_kept() {
+    let maps = Maps {
+        list: vec![None, Some(1)],
+        ..Maps::default()
+    };
+    let patch = to_merge_patch(&maps).unwrap();
+    let mut patched = Maps::default();
+    apply_merge_patch(&mut patched, patch).unwrap();
+    assert_eq!(patched, maps);
+}
+
+fn option_map() -> impl Strategy<Value = BTreeMap<String, Option<u8>>> {
+    prop::collection::btree_map("[ab]", any::<Option<u8>>(), 0..3)
+}
+
+fn maps() -> impl Strategy<Value = Maps> {
+    (
+        option_map(),
+        prop::collection::btree_map("[ab]", option_map(), 0..3),
+        prop::collection::vec(any::<Option<u8>>(), 0..3),
+        any::<Option<bool>>(),
+    )
+        .prop_map(|(limits, nested, list, tls)| Maps {
+            limits,
+            nested,
+            list,
+            tls: tls.map(|enabled| Tls {
+                enabled,
+                cert: String::new(),
+            }),
+        })
+}
+
+fn logging() -> impl Strategy<Value = Logging> {
+    (any::<Option<u8>>(), any::<Option<bool>>()).prop_map(|(level, file)| Logging {
+        level,
+        file: file.map(|b| b.to_string()),
+    })
+}
+
+/// A patch either restores the value exactly or is refused.
+fn check_round_trip<T>(value: &T)
+where
+    T: core::fmt::Debug + PartialEq + Default + IsDefault + Serialize + serde::de::DeserializeOwned,
+{
+    if let Ok(patch) = to_merge_patch(value) {
+        let mut patched = T::default();
+        apply_merge_patch(&mut patched, patch).unwrap();
+        assert_eq!(&patched, value);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, IsBaseline, Serialize, Deserialize)]
+struct Profile {
+    workers: u8,
+    log: Option<String>,
+    limits: BTreeMap<String, u32>,
+}
+
+fn prod() -> Profile {
+    Profile {
+        workers: 8,
+        log: Some("warn".into()),
+        limits: BTreeMap::from([("files".into(), 1024), ("memory".into(), 1 << 20)]),
+    }
+}
+
+#[test]
+fn patch_from_baseline() {
+    let baseline = prod();
+    assert_eq!(to_merge_patch_from(&baseline, &prod()).unwrap(), json!({}));
+
+    let mut profile = prod();
+    profile.workers = 2;
+    profile.limits.remove("memory");
+    let patch = to_merge_patch_from(&baseline, &profile).unwrap();
+    assert_eq!(patch, json!({ "workers": 2, "limits": { "memory": null } }));
+
+    let mut patched = prod();
+    apply_merge_patch(&mut patched, patch).unwrap();
+    assert_eq!(patched, profile);
+}
+
+#[test]
+fn patch_from_baseline_rejects_null() {
+    let profile = Profile {
+        log: None,
+        ..prod()
+    };
+    let err = to_merge_patch_from(&prod(), &profile).unwrap_err();
+    assert!(err.to_string().contains("`log`"));
+}
+
+#[test]
+fn mask_against_baseline() {
+    assert_eq!(mask_baseline(&prod(), &prod()).unwrap(), json!({}));
+
+    let mut profile = Profile {
+        log: None,
+        ..prod()
+    };
+    profile.limits.insert("files".into(), 64);
+    assert_eq!(
+        mask_baseline(&prod(), &profile).unwrap(),
+        json!({ "log": null, "limits": { "files": 64 } })
+    );
+}
+
+proptest! {
+    #[test]
+    fn round_trip_maps(value in maps()) {
+        check_round_trip(&value);
+    }
+
+    #[test]
+    fn round_trip_serde_default(value in logging()) {
+        check_round_trip(&value);
+    }
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "std")]
+
+use is_default::{DefaultAsNone, IsDefault, NonDefault};
+use std::time::Duration;
+
+#[test]
+fn new() {
+    assert!(NonDefault::new(String::new()).is_none());
+    assert!(NonDefault::new(Vec::<String>::new()).is_none());
+    assert!(NonDefault::new(Duration::ZERO).is_none());
+
+    let name = NonDefault::new(String::from("alice")).unwrap();
+    assert_eq!(name.len(), 5);
+    assert_eq!(name.get(), "alice");
+    assert_eq!(name.to_string(), "alice");
+    assert!(!name.is_default());
+    assert_eq!(name.into_inner(), "alice");
+
+    let timeout = NonDefault::new_unchecked(Duration::from_secs(1));
+    assert_eq!(*timeout, Duration::from_secs(1));
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "default value")]
+fn new_unchecked_default() {
+    NonDefault::new_unchecked(0u8);
+}
+
+#[test]
+fn default_as_none() {
+    let v: DefaultAsNone<u32> = NonDefault::new(5).into();
+    assert_eq!(v.get(), Some(&5));
+
+    let v: DefaultAsNone<u32> = None::<NonDefault<u32>>.into();
+    assert!(v.is_none());
+
+    let back: Option<NonDefault<u32>> = DefaultAsNone::new(9).into();
+    assert_eq!(back.as_deref(), Some(&9));
+    let back: Option<NonDefault<u32>> = DefaultAsNone::new(0).into();
+    assert!(back.is_none());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct User {
+        #[allow(dead_code)]
+        name: NonDefault<String>,
+    }
+
+    let user: User = serde_json::from_str(r#"{"name":"bob"}"#).unwrap();
+    assert_eq!(serde_json::to_string(&user.name).unwrap(), r#""bob""#);
+
+    let err = serde_json::from_str::<User>(r#"{"name":""}"#).unwrap_err();
+    assert!(
+        err.to_string().contains("expected a non-default value"),
+        "{err}"
+    );
+
+    let hosts: Option<NonDefault<Vec<String>>> = serde_json::from_str("null").unwrap();
+    assert!(hosts.is_none());
+    assert!(serde_json::from_str::<NonDefault<Vec<String>>>("[]").is_err());
+}
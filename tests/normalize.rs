@@ -0,0 +1,135 @@
+use is_default::NormalizeDefaults;
+
+#[test]
+fn option_recurses_without_collapsing() {
+    let mut v = Some(0u8);
+    v.normalize_defaults();
+    assert_eq!(v, Some(0));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn maps() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let mut m: BTreeMap<&str, Vec<u8>> = [("a", vec![]), ("b", vec![1])].into();
+    m.normalize_defaults();
+    assert_eq!(m, [("b", vec![1])].into());
+
+    let mut m: HashMap<u8, BTreeMap<u8, u8>> = [(1, [(1, 0)].into()), (2, [(1, 1)].into())].into();
+    m.normalize_defaults();
+    assert_eq!(m, [(2, [(1, 1)].into())].into());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn helpers() {
+    use is_default::normalize::{collapse_option, trim_trailing};
+
+    let mut v = Some(String::new());
+    collapse_option(&mut v);
+    assert_eq!(v, None);
+
+    let mut v = vec![0, 1, 0, 0];
+    trim_trailing(&mut v);
+    assert_eq!(v, [0, 1]);
+}
+
+#[cfg(all(feature = "derive", feature = "std", not(feature = "via_default_eq")))]
+mod derive {
+    use is_default::{IsDefault, NormalizeDefaults};
+    use std::collections::BTreeMap;
+
+    #[derive(Clone, Debug, Default, PartialEq, IsDefault, NormalizeDefaults)]
+    struct Limits {
+        #[normalize(collapse)]
+        memory: Option<u64>,
+        #[normalize(collapse)]
+        paths: Option<Vec<String>>,
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, IsDefault, NormalizeDefaults)]
+    struct Config {
+        limits: Option<Limits>,
+        #[normalize(collapse)]
+        nested: Option<Limits>,
+        #[normalize(trim)]
+        weights: Vec<f32>,
+        raw: Vec<u8>,
+        env: BTreeMap<String, String>,
+        #[normalize(skip)]
+        keep: BTreeMap<String, String>,
+    }
+
+    #[derive(Clone, Debug, PartialEq, NormalizeDefaults)]
+    enum Source {
+        File(#[normalize(collapse)] Option<u32>),
+        Inline { values: BTreeMap<u8, u8> },
+    }
+
+    #[test]
+    fn structs() {
+        let mut c = Config {
+            limits: Some(Limits {
+                memory: Some(0),
+                paths: Some(vec![]),
+            }),
+            nested: Some(Limits {
+                memory: Some(0),
+                paths: None,
+            }),
+            weights: vec![1.0, 0.0, -0.0],
+            raw: vec![1, 0],
+            env: [("A".into(), String::new())].into(),
+            keep: [("B".into(), String::new())].into(),
+        };
+        c.normalize_defaults();
+
+        assert_eq!(
+            c,
+            Config {
+                limits: Some(Limits::default()),
+                nested: None,
+                weights: vec![1.0],
+                raw: vec![1, 0],
+                env: BTreeMap::new(),
+                keep: [("B".into(), String::new())].into(),
+            }
+        );
+    }
+
+    #[test]
+    fn enums() {
+        let mut s = Source::File(Some(0));
+        s.normalize_defaults();
+        assert_eq!(s, Source::File(None));
+
+        let mut s = Source::Inline {
+            values: [(1, 0), (2, 2)].into(),
+        };
+        s.normalize_defaults();
+        assert_eq!(
+            s,
+            Source::Inline {
+                values: [(2, 2)].into()
+            }
+        );
+    }
+
+    #[test]
+    fn eq_normalized() {
+        let a = Config {
+            nested: Some(Limits::default()),
+            weights: vec![0.0],
+            ..Default::default()
+        };
+        let b = Config::default();
+        assert_ne!(a, b);
+        assert!(a.eq_normalized(&b));
+        assert_ne!(a, b);
+        assert!(!a.eq_normalized(&Config {
+            raw: vec![0],
+            ..Default::default()
+        }));
+    }
+}
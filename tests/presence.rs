@@ -0,0 +1,74 @@
+use is_default::Presence;
+
+#[test]
+fn api() {
+    let mut v = Presence::<u32>::Unset;
+    assert!(v.is_unset());
+    assert_eq!(v.get(), None);
+    assert_eq!(v.effective(), 0);
+
+    assert_eq!(v.set(0), None);
+    assert!(v.is_set());
+    assert_eq!(v.get(), Some(&0));
+    assert_eq!(v.effective(), 0);
+
+    assert_eq!(v.set(5), Some(0));
+    assert_eq!(v.unset(), Some(5));
+    assert!(v.is_unset());
+}
+
+#[test]
+fn overlay() {
+    let base = Presence::Set(3u8);
+    assert_eq!(Presence::Unset.or(base), base);
+    assert_eq!(Presence::Set(0).or(base), Presence::Set(0));
+    assert_eq!(Presence::<u8>::Unset.or(Presence::Unset), Presence::Unset);
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(Presence::from(Some(0u8)), Presence::Set(0));
+    assert_eq!(Presence::from(None::<u8>), Presence::Unset);
+    assert_eq!(Option::from(Presence::Set(1u8)), Some(1));
+    assert_eq!(Presence::Set(1u8).into_option(), Some(1));
+    assert_eq!(Presence::<u8>::default(), Presence::Unset);
+}
+
+#[cfg(not(feature = "via_default_eq"))]
+#[test]
+fn is_default() {
+    use is_default::{IsDefault, ResetDefault};
+
+    assert!(Presence::<u8>::Unset.is_default());
+    assert!(!Presence::Set(0u8).is_default());
+
+    let mut v = Presence::Set(1u8);
+    v.reset_default();
+    assert!(v.is_unset());
+}
+
+#[cfg(all(feature = "serde", feature = "std"))]
+#[test]
+fn serde() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(skip_serializing_if = "Presence::is_unset")]
+        retries: Presence<u8>,
+    }
+
+    let config: Config = serde_json::from_str("{}").unwrap();
+    assert_eq!(config.retries, Presence::Unset);
+    assert_eq!(serde_json::to_string(&config).unwrap(), "{}");
+
+    let config: Config = serde_json::from_str(r#"{"retries":null}"#).unwrap();
+    assert_eq!(config.retries, Presence::Unset);
+
+    let config: Config = serde_json::from_str(r#"{"retries":0}"#).unwrap();
+    assert_eq!(config.retries, Presence::Set(0));
+    assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"retries":0}"#);
+
+    let config: Config = serde_json::from_str(r#"{"retries":3}"#).unwrap();
+    assert_eq!(config.retries.effective(), 3);
+}
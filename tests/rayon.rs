@@ -0,0 +1,77 @@
+#![cfg(feature = "rayon")]
+
+use is_default::ParIsDefault;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+#[test]
+fn slices() {
+    let mut v = vec![0u32; 100_000];
+    assert!(v.par_is_default());
+    assert_eq!(v.par_count_non_default(), 0);
+    assert_eq!(v.par_position_non_default(), None);
+
+    v[70_000] = 1;
+    v[99_999] = 2;
+    assert!(!v.par_is_default());
+    assert!(!v.as_slice().par_is_default());
+    assert_eq!(v.par_count_non_default(), 2);
+    assert_eq!(v.par_position_non_default(), Some(70_000));
+
+    assert!(Vec::<u8>::new().par_is_default());
+}
+
+#[test]
+fn arrays() {
+    let mut a = [0.0f64; 5000];
+    a[1] = -0.0;
+    assert!(a.par_is_default());
+    a[4000] = 0.5;
+    assert_eq!(a.par_position_non_default(), Some(4000));
+}
+
+#[test]
+fn vec_deque() {
+    let mut v: VecDeque<u8> = (0..10).map(|_| 0).collect();
+    v.rotate_left(3);
+    v.push_front(1);
+    v.push_back(2);
+    assert!(!v.par_is_default());
+    assert_eq!(v.par_count_non_default(), 2);
+    assert_eq!(v.par_position_non_default(), Some(0));
+
+    v.pop_front();
+    assert_eq!(v.par_position_non_default(), Some(10));
+}
+
+#[test]
+fn maps() {
+    let mut hash: HashMap<&str, u8> = [("a", 0), ("b", 0)].into();
+    assert!(hash.par_is_default());
+    hash.insert("c", 3);
+    assert!(!hash.par_is_default());
+    assert_eq!(hash.par_count_non_default(), 1);
+    assert_eq!(hash.par_position_non_default(), Some(&"c"));
+
+    let mut btree: BTreeMap<u8, String> = [(1, String::new())].into();
+    assert!(btree.par_is_default());
+    btree.insert(2, "x".into());
+    assert_eq!(btree.par_position_non_default(), Some(&2));
+}
+
+#[cfg(all(feature = "derive", not(feature = "via_default_eq")))]
+#[test]
+fn derived() {
+    use is_default::IsDefault;
+
+    #[derive(Clone, Default, IsDefault)]
+    struct Record {
+        id: u64,
+        name: String,
+        score: f32,
+    }
+
+    let mut records = vec![Record::default(); 10_000];
+    assert!(records.par_is_default());
+    records[1234].score = 1.0;
+    assert_eq!(records.par_position_non_default(), Some(1234));
+}
@@ -0,0 +1,233 @@
+#![cfg_attr(feature = "ascii_char", feature(ascii_char, ascii_char_variants))]
+#![cfg_attr(feature = "bstr", feature(bstr))]
+#![cfg_attr(feature = "f16", feature(f16))]
+#![cfg_attr(feature = "f128", feature(f128))]
+
+use is_default::{IsDefault, ResetDefault};
+
+macro_rules! test {
+    ($ty:ident; $( $false:expr ),* ) => {
+        test!($ty, $ty; $( $false ),* );
+    };
+
+    ($fn:ident, $ty:ty; $( $false:expr ),* ) => {
+        #[test]
+        fn $fn() {
+            $({
+                let mut x: $ty = $false;
+                assert!(!x.is_default());
+                x.reset_default();
+                assert!(x.is_default());
+            })*
+        }
+    };
+}
+
+test!(bool; true);
+test!(char; 'a', char::MAX);
+
+#[cfg(feature = "f16")]
+test!(f16; f16::MAX, f16::NAN);
+
+test!(f32; f32::MAX, f32::NAN);
+test!(f64; f64::MAX, f64::NAN);
+
+#[cfg(feature = "f128")]
+test!(f128; f128::MAX, f128::NAN);
+
+test!(i8; i8::MAX, i8::MIN);
+test!(i16; i16::MAX, i16::MIN);
+test!(i32; i32::MAX, i32::MIN);
+test!(i64; i64::MAX, i64::MIN);
+test!(i128; i128::MAX, i128::MIN);
+test!(isize; isize::MAX, isize::MIN);
+
+test!(u8; u8::MAX);
+test!(u16; u16::MAX);
+test!(u32; u32::MAX);
+test!(u64; u64::MAX);
+test!(u128; u128::MAX);
+test!(usize; usize::MAX);
+
+test!(array, [u8; 2]; [0u8, 1u8], [1u8, 1u8]);
+test!(tuple, (u8, bool, char); (1, false, '\x00'), (0, true, 'x'));
+
+#[test]
+fn ref_mut() {
+    let mut x = 1u8;
+    let r = &mut x;
+    r.reset_default();
+    assert!(r.is_default());
+    assert_eq!(x, 0);
+}
+
+#[cfg(not(feature = "via_default_eq"))]
+#[test]
+fn slice() {
+    let mut xs = [1u8, 2, 3];
+    let s: &mut [u8] = &mut xs;
+    assert!(!s.is_default());
+    s.reset_default();
+    assert!(s.is_default());
+}
+
+#[test]
+fn take_if_non_default() {
+    let mut x = 5u8;
+    assert_eq!(x.take_if_non_default(), Some(5));
+    assert_eq!(x, 0);
+    assert_eq!(x.take_if_non_default(), None);
+}
+
+#[cfg(feature = "ascii_char")]
+mod ascii_char {
+    use core::ascii::Char;
+    use is_default::{IsDefault, ResetDefault};
+    test!(ascii_char, Char; Char::StartOfHeading, Char::MAX);
+}
+
+#[cfg(feature = "std")]
+mod std_types {
+    use is_default::{IsDefault, ResetDefault};
+    use std::{
+        borrow::Cow,
+        cell::{Cell, OnceCell, RefCell},
+        collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque},
+        ffi::{CString, OsString},
+        io::Cursor,
+        num::Wrapping,
+        path::PathBuf,
+        rc::Rc,
+        sync::{Arc, OnceLock},
+        time::Duration,
+    };
+
+    test!(wrapping, Wrapping<u8>; Wrapping(1u8));
+
+    test!(string, String; String::from("x"));
+    test!(c_string, CString; CString::new("x").unwrap());
+    test!(os_string, OsString; OsString::from("x"));
+    test!(path_buf, PathBuf; PathBuf::from("x"));
+
+    test!(duration, Duration; Duration::new(1, 0));
+    test!(option, Option<u8>; Some(0u8));
+
+    test!(b_tree_set, BTreeSet<u8>; BTreeSet::from([0u8]));
+    test!(hash_set, HashSet<u8>; HashSet::from([0u8]));
+    test!(linked_list, LinkedList<u8>; LinkedList::from([0u8]));
+
+    test!(vec, Vec<u8>; Vec::from([0u8]));
+    test!(vec_deque, VecDeque<u8>; VecDeque::from([0u8]));
+
+    test!(b_tree_map, BTreeMap<u8, u8>; BTreeMap::from([(0u8, 0u8)]));
+    test!(hash_map, HashMap<u8, u8>; HashMap::from([(0u8, 0u8)]));
+
+    test!(arc, Arc<u8>; Arc::new(1u8));
+    test!(test_box, Box<u8>; Box::new(1u8));
+    test!(rc, Rc<u8>; Rc::new(1u8));
+
+    test!(cow_borrowed, Cow<str>; Cow::from("x"));
+    test!(cow_owned, Cow<str>; Cow::from(String::from("x")));
+    test!(cell, Cell<u8>; Cell::new(1u8));
+    test!(ref_cell, RefCell<u8>; RefCell::new(1u8));
+
+    test!(once_cell, OnceCell<u8>; OnceCell::from(0u8));
+    test!(once_lock, OnceLock<u8>; OnceLock::from(0u8));
+
+    test!(cursor, Cursor<[u8; 1]>; {
+        let mut c = Cursor::new([0u8; 1]);
+        c.set_position(1);
+        c
+    });
+
+    #[cfg(not(feature = "via_default_eq"))]
+    #[test]
+    fn vec_keeps_capacity() {
+        let mut v = Vec::<u8>::with_capacity(16);
+        v.push(1);
+        v.reset_default();
+        assert!(v.is_default());
+        assert!(v.capacity() >= 16);
+    }
+
+    #[test]
+    fn shared_arc() {
+        let mut x = Arc::new(1u8);
+        let y = Arc::clone(&x);
+        x.reset_default();
+        assert!(x.is_default());
+        assert!(!y.is_default());
+    }
+
+    #[test]
+    fn take_if_non_default() {
+        let mut v = vec![1u8, 2];
+        assert_eq!(v.take_if_non_default(), Some(vec![1u8, 2]));
+        assert!(v.is_default());
+        assert_eq!(v.take_if_non_default(), None);
+    }
+
+    #[cfg(not(feature = "via_default_eq"))]
+    mod no_via_default_eq {
+        use is_default::{IsDefault, ResetDefault};
+        use std::{
+            collections::BinaryHeap,
+            rc::{self, Rc},
+            sync::{
+                Mutex, RwLock,
+                atomic::{
+                    AtomicBool, AtomicI8, AtomicI16, AtomicI32, AtomicI64, AtomicIsize, AtomicU8,
+                    AtomicU16, AtomicU32, AtomicU64, AtomicUsize,
+                },
+            },
+        };
+
+        test!(atomic_bool, AtomicBool; AtomicBool::new(true));
+
+        test!(atomic_i8, AtomicI8; AtomicI8::new(i8::MAX));
+        test!(atomic_i16, AtomicI16; AtomicI16::new(i16::MAX));
+        test!(atomic_i32, AtomicI32; AtomicI32::new(i32::MAX));
+        test!(atomic_i64, AtomicI64; AtomicI64::new(i64::MAX));
+        test!(atomic_isize, AtomicIsize; AtomicIsize::new(isize::MAX));
+
+        test!(atomic_u8, AtomicU8; AtomicU8::new(u8::MAX));
+        test!(atomic_u16, AtomicU16; AtomicU16::new(u16::MAX));
+        test!(atomic_u32, AtomicU32; AtomicU32::new(u32::MAX));
+        test!(atomic_u64, AtomicU64; AtomicU64::new(u64::MAX));
+        test!(atomic_usize, AtomicUsize; AtomicUsize::new(usize::MAX));
+
+        test!(binary_heap, BinaryHeap<u8>; BinaryHeap::from([0u8]));
+
+        test!(rw_lock, RwLock<u8>; RwLock::new(1u8));
+        test!(mutex, Mutex<u8>; Mutex::new(1u8));
+
+        #[test]
+        fn poisoned_mutex() {
+            let mut m = Mutex::new(1u8);
+            let _ = std::panic::catch_unwind(|| {
+                let _guard = m.lock().unwrap();
+                panic!();
+            });
+            assert!(m.is_poisoned());
+            m.reset_default();
+            assert!(m.is_default());
+        }
+
+        #[test]
+        fn rc_weak() {
+            let x = Rc::new(0u8);
+            let mut xw: rc::Weak<u8> = Rc::downgrade(&x);
+            assert!(!xw.is_default());
+            xw.reset_default();
+            assert!(xw.is_default());
+        }
+    }
+
+    #[cfg(feature = "bstr")]
+    mod feature_bstr {
+        use is_default::{IsDefault, ResetDefault};
+        use std::bstr::ByteString;
+
+        test!(byte_string, ByteString; ByteString::from_iter(*b"x"));
+    }
+}
@@ -0,0 +1,185 @@
+use is_default::IsDefault;
+use serde::Serialize;
+
+#[test]
+fn skip_serializing_if() {
+    #[derive(Serialize)]
+    struct Request<'a> {
+        #[serde(skip_serializing_if = "is_default::is_default")]
+        id: u32,
+        #[serde(skip_serializing_if = "is_default::is_default")]
+        name: &'a str,
+    }
+
+    let json = serde_json::to_string(&Request { id: 0, name: "" }).unwrap();
+    assert_eq!(json, "{}");
+    let json = serde_json::to_string(&Request { id: 1, name: "x" }).unwrap();
+    assert_eq!(json, r#"{"id":1,"name":"x"}"#);
+    assert!(is_default::is_default(&0u8));
+    assert!(!is_default::is_default(&"x"));
+    assert!(0u8.is_default());
+}
+
+#[cfg(all(feature = "serde", not(feature = "via_default_eq")))]
+mod feature_serde {
+    use is_default::serde::DefaultAsNull;
+    use serde::{Deserialize, Serialize};
+    use serde_with::{DisplayFromStr, serde_as};
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde_as(as = "DefaultAsNull")]
+        #[serde(default)]
+        retries: u8,
+        #[serde_as(as = "DefaultAsNull<DisplayFromStr>")]
+        #[serde(default)]
+        port: u16,
+    }
+
+    #[test]
+    fn default_as_null() {
+        let config = Config {
+            retries: 0,
+            port: 8080,
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"retries":null,"port":"8080"}"#);
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+
+        let config = Config {
+            retries: 3,
+            port: 0,
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"retries":3,"port":null}"#);
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+    }
+
+    #[test]
+    fn default_as_null_missing() {
+        let config = serde_json::from_str::<Config>("{}").unwrap();
+        assert_eq!(
+            config,
+            Config {
+                retries: 0,
+                port: 0
+            }
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn skip_default() {
+        use is_default::serde::SkipDefault;
+        use std::collections::{BTreeMap, HashMap, VecDeque};
+
+        #[serde_as]
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Collections {
+            #[serde_as(as = "SkipDefault")]
+            vec: Vec<u8>,
+            #[serde_as(as = "SkipDefault")]
+            vec_deque: VecDeque<String>,
+            #[serde_as(as = "SkipDefault<DisplayFromStr>")]
+            b_tree_map: BTreeMap<String, u32>,
+            #[serde_as(as = "SkipDefault")]
+            hash_map: HashMap<String, Option<u8>>,
+        }
+
+        let c = Collections {
+            vec: vec![0, 1, 0, 2],
+            vec_deque: VecDeque::from([String::new(), "x".into()]),
+            b_tree_map: BTreeMap::from([("a".into(), 0), ("b".into(), 1)]),
+            hash_map: HashMap::from([("a".into(), None), ("b".into(), Some(0))]),
+        };
+        let json = serde_json::to_string(&c).unwrap();
+        assert_eq!(
+            json,
+            r#"{"vec":[1,2],"vec_deque":["x"],"b_tree_map":{"b":"1"},"hash_map":{"b":0}}"#
+        );
+
+        let c = serde_json::from_str::<Collections>(&json).unwrap();
+        assert_eq!(c.vec, [1, 2]);
+        assert_eq!(c.b_tree_map, BTreeMap::from([("b".into(), 1)]));
+    }
+}
+
+#[cfg(feature = "derive")]
+mod skip_defaults {
+    use serde::{Deserialize, Serialize};
+
+    #[is_default::skip_defaults]
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        retries: u8,
+        #[serde(rename = "ratio")]
+        scale: f32,
+        #[skip_defaults(keep)]
+        port: u16,
+        #[serde(skip_serializing_if = "is_odd")]
+        odd: u8,
+    }
+
+    fn is_odd(v: &u8) -> bool {
+        v % 2 == 1
+    }
+
+    #[is_default::skip_defaults(default)]
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct WithDefault {
+        retries: u8,
+        #[serde(default = "one")]
+        port: u16,
+    }
+
+    fn one() -> u16 {
+        1
+    }
+
+    #[is_default::skip_defaults]
+    #[derive(Serialize)]
+    enum Message {
+        Ping { id: u32 },
+        Raw(u8),
+    }
+
+    #[test]
+    fn skip_defaults() {
+        let json = serde_json::to_string(&Config::default()).unwrap();
+        assert_eq!(json, r#"{"port":0,"odd":0}"#);
+
+        let config = Config {
+            retries: 1,
+            scale: 0.5,
+            port: 8080,
+            odd: 1,
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"retries":1,"ratio":0.5,"port":8080}"#);
+        assert!(serde_json::from_str::<Config>("{}").is_err());
+    }
+
+    #[test]
+    fn skip_defaults_with_default() {
+        let json = serde_json::to_string(&WithDefault::default()).unwrap();
+        assert_eq!(json, "{}");
+
+        let v = serde_json::from_str::<WithDefault>("{}").unwrap();
+        assert_eq!(
+            v,
+            WithDefault {
+                retries: 0,
+                port: 1
+            }
+        );
+    }
+
+    #[test]
+    fn skip_defaults_enum() {
+        let json = serde_json::to_string(&Message::Ping { id: 0 }).unwrap();
+        assert_eq!(json, r#"{"Ping":{}}"#);
+        let json = serde_json::to_string(&Message::Raw(0)).unwrap();
+        assert_eq!(json, r#"{"Raw":0}"#);
+    }
+}
@@ -0,0 +1,98 @@
+#![cfg(not(feature = "via_default_eq"))]
+
+use is_default::IsDefault;
+use proptest::prelude::*;
+
+/// Checks that the bulk path agrees with the element-wise loop.
+fn check<T: IsDefault>(v: &[T]) {
+    let expected = v.iter().all(IsDefault::is_default);
+    assert_eq!(v.is_default(), expected);
+    assert_eq!(T::is_default_slice(v), expected);
+}
+
+/// Mostly default elements with a few others, at any position and length.
+fn sparse<T: Default + Clone + core::fmt::Debug>(
+    value: impl Strategy<Value = T>,
+) -> impl Strategy<Value = Vec<T>> {
+    (
+        0usize..600,
+        prop::collection::vec((0usize..600, value), 0..3),
+    )
+        .prop_map(|(len, set)| {
+            let mut v = vec![T::default(); len];
+            for (i, x) in set {
+                if i < len {
+                    v[i] = x;
+                }
+            }
+            v
+        })
+}
+
+macro_rules! int_tests {
+    ($($name:ident: $t:ty),*) => {
+        proptest! {
+            $(
+                #[test]
+                fn $name(v in sparse(any::<$t>())) {
+                    check(&v);
+                    check(&v[v.len() / 3..]);
+                }
+            )*
+        }
+    };
+}
+
+int_tests!(
+    u8s: u8, u16s: u16, u32s: u32, u64s: u64, u128s: u128, usizes: usize,
+    i8s: i8, i16s: i16, i32s: i32, i64s: i64, i128s: i128, isizes: isize
+);
+
+fn special_f32() -> impl Strategy<Value = f32> {
+    prop_oneof![
+        Just(-0.0f32),
+        Just(f32::NAN),
+        Just(f32::MIN_POSITIVE),
+        Just(-f32::from_bits(1)),
+        any::<f32>(),
+    ]
+}
+
+fn special_f64() -> impl Strategy<Value = f64> {
+    prop_oneof![
+        Just(-0.0f64),
+        Just(f64::NAN),
+        Just(f64::MIN_POSITIVE),
+        Just(-f64::from_bits(1)),
+        any::<f64>(),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn f32s(v in sparse(special_f32())) {
+        check(&v);
+        check(&v[v.len() / 3..]);
+    }
+
+    #[test]
+    fn f64s(v in sparse(special_f64())) {
+        check(&v);
+        check(&v[v.len() / 3..]);
+    }
+}
+
+#[test]
+fn negative_zero() {
+    assert!([0.0f32, -0.0, 0.0].is_default());
+    assert!([-0.0f64; 300].is_default());
+    assert!(![f32::NAN; 3].is_default());
+}
+
+#[test]
+fn arrays() {
+    let mut a = [0u32; 1000];
+    assert!(a.is_default());
+    a[999] = 1;
+    assert!(!a.is_default());
+}
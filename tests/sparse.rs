@@ -0,0 +1,226 @@
+#![cfg(feature = "sparse")]
+
+use is_default::{
+    SparseDecode, SparseEncode,
+    sparse::{DecodeError, decode_varint, encode_varint},
+};
+
+macro_rules! test {
+    ($fn:ident, $ty:ty; $( $v:expr => $bytes:expr ),* ) => {
+        #[test]
+        fn $fn() {
+            $({
+                let v: $ty = $v;
+                let bytes = v.to_sparse_bytes();
+                assert_eq!(bytes, $bytes);
+                assert_eq!(<$ty>::from_sparse_bytes(&bytes).unwrap(), v);
+            })*
+        }
+    };
+}
+
+test!(bool, bool; false => [0], true => [1]);
+test!(u8, u8; 0 => [0], 255 => [255]);
+test!(i8, i8; -1 => [255]);
+test!(u16, u16; 300 => [0xac, 0x02]);
+test!(u32, u32; 127 => [0x7f], 128 => [0x80, 0x01]);
+test!(u64, u64; u64::MAX => [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]);
+test!(i32, i32; 0 => [0], -1 => [1], 1 => [2], -2 => [3]);
+test!(i64, i64; i64::MIN => [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]);
+test!(f32, f32; 1.0 => [0, 0, 0x80, 0x3f]);
+test!(char, char; 'a' => [0x61]);
+test!(string, String; String::from("hi") => [2, b'h', b'i']);
+test!(vec, Vec<u16>; vec![1, 300] => [2, 1, 0xac, 0x02]);
+test!(option, Option<u8>; None => [0], Some(0) => [1, 0]);
+test!(array, [u8; 2]; [1, 2] => [1, 2]);
+
+#[test]
+fn varint_bounds() {
+    let mut out = Vec::new();
+    encode_varint(u128::MAX, &mut out);
+    assert_eq!(out.len(), 19);
+    assert_eq!(decode_varint(&mut out.as_slice()), Ok(u128::MAX));
+
+    let mut overflow = [0xffu8; 19].to_vec();
+    overflow.push(0x01);
+    assert_eq!(
+        decode_varint(&mut overflow.as_slice()),
+        Err(DecodeError::VarintOverflow)
+    );
+    assert_eq!(
+        decode_varint(&mut [0x80u8].as_slice()),
+        Err(DecodeError::UnexpectedEof)
+    );
+}
+
+#[test]
+fn decode_errors() {
+    assert_eq!(
+        bool::from_sparse_bytes(&[2]),
+        Err(DecodeError::InvalidValue)
+    );
+    assert_eq!(
+        u8::from_sparse_bytes(&[1, 2]),
+        Err(DecodeError::TrailingBytes)
+    );
+    assert_eq!(u8::from_sparse_bytes(&[]), Err(DecodeError::UnexpectedEof));
+    assert_eq!(
+        u16::from_sparse_bytes(&[0x80, 0x80, 0x04]),
+        Err(DecodeError::VarintOverflow)
+    );
+    assert_eq!(
+        String::from_sparse_bytes(&[1, 0xff]),
+        Err(DecodeError::InvalidValue)
+    );
+}
+
+#[test]
+fn vec_length_beyond_input() {
+    let mut bytes = Vec::new();
+    encode_varint(u64::MAX.into(), &mut bytes);
+    bytes.push(1);
+    assert_eq!(
+        Vec::<u64>::from_sparse_bytes(&bytes),
+        Err(DecodeError::UnexpectedEof)
+    );
+}
+
+#[cfg(all(feature = "derive", not(feature = "via_default_eq")))]
+mod derive {
+    use is_default::{IsDefault, SparseDecode, SparseEncode};
+
+    #[derive(Debug, Default, PartialEq, IsDefault, SparseEncode, SparseDecode)]
+    struct Inner {
+        a: u8,
+        b: String,
+    }
+
+    #[derive(Debug, Default, PartialEq, IsDefault, SparseEncode, SparseDecode)]
+    struct Telemetry {
+        id: u32,
+        temperature: f32,
+        inner: Inner,
+        samples: Vec<i16>,
+        label: Option<String>,
+    }
+
+    #[test]
+    fn default_is_single_byte() {
+        assert_eq!(Telemetry::default().to_sparse_bytes(), [0]);
+        assert_eq!(
+            Telemetry::from_sparse_bytes(&[0]).unwrap(),
+            Telemetry::default()
+        );
+    }
+
+    #[test]
+    fn byte_stable() {
+        let t = Telemetry {
+            id: 300,
+            inner: Inner {
+                a: 0,
+                b: String::from("x"),
+            },
+            ..Telemetry::default()
+        };
+        let bytes = t.to_sparse_bytes();
+        // presence: id (bit 0) and inner (bit 2)
+        // id: len 2, varint 300
+        // inner: len 4, presence b (bit 1), b: len 2, string "x"
+        assert_eq!(bytes, [0b101, 2, 0xac, 0x02, 4, 0b10, 2, 1, b'x']);
+        assert_eq!(Telemetry::from_sparse_bytes(&bytes).unwrap(), t);
+    }
+
+    #[test]
+    fn round_trip() {
+        let t = Telemetry {
+            id: 1,
+            temperature: -1.5,
+            inner: Inner {
+                a: 7,
+                b: String::new(),
+            },
+            samples: vec![-1, 0, 1],
+            label: Some(String::new()),
+        };
+        let bytes = t.to_sparse_bytes();
+        assert_eq!(Telemetry::from_sparse_bytes(&bytes).unwrap(), t);
+    }
+
+    #[test]
+    fn long_fields() {
+        for len in [127, 128, 300, 20_000] {
+            let t = Telemetry {
+                inner: Inner {
+                    a: 1,
+                    b: "x".repeat(len),
+                },
+                label: Some("y".repeat(len)),
+                ..Telemetry::default()
+            };
+            let bytes = t.to_sparse_bytes();
+            assert_eq!(Telemetry::from_sparse_bytes(&bytes).unwrap(), t);
+        }
+    }
+
+    #[derive(Debug, Default, PartialEq, IsDefault, SparseEncode, SparseDecode)]
+    struct V1 {
+        #[sparse(index = 0)]
+        id: u32,
+        #[sparse(index = 2)]
+        name: String,
+    }
+
+    #[derive(Debug, Default, PartialEq, IsDefault, SparseEncode, SparseDecode)]
+    struct V2 {
+        #[sparse(index = 2)]
+        name: String,
+        #[sparse(index = 5)]
+        flags: u64,
+        #[sparse(index = 0)]
+        id: u32,
+    }
+
+    #[test]
+    fn schema_evolution() {
+        let v2 = V2 {
+            name: String::from("n"),
+            flags: 9,
+            id: 4,
+        };
+        let v1 = V1::from_sparse_bytes(&v2.to_sparse_bytes()).unwrap();
+        assert_eq!(
+            v1,
+            V1 {
+                id: 4,
+                name: String::from("n")
+            }
+        );
+
+        let v2 = V2::from_sparse_bytes(&v1.to_sparse_bytes()).unwrap();
+        assert_eq!(
+            v2,
+            V2 {
+                name: String::from("n"),
+                flags: 0,
+                id: 4,
+            }
+        );
+    }
+
+    #[derive(Debug, Default, PartialEq, IsDefault, SparseEncode, SparseDecode)]
+    struct Tuple(u8, #[sparse(index = 100)] u8);
+
+    #[derive(Debug, Default, PartialEq, IsDefault, SparseEncode, SparseDecode)]
+    struct Unit;
+
+    #[test]
+    fn tuple_and_unit() {
+        let t = Tuple(0, 1);
+        let bytes = t.to_sparse_bytes();
+        assert_eq!(bytes.len(), 15 + 2);
+        assert_eq!(Tuple::from_sparse_bytes(&bytes).unwrap(), t);
+        assert_eq!(Unit.to_sparse_bytes(), [0]);
+        assert_eq!(Unit::from_sparse_bytes(&[0]).unwrap(), Unit);
+    }
+}
@@ -64,6 +64,7 @@ test!(array, [u8; 0], [0u8]; [1u8]);
 macro_rules! test_tuple {
     ($fn:ident, $( $Ts:tt)* ) => {
         #[test]
+        #[allow(unused_assignments)]
         fn $fn() {
             let mut tuple = ( $($Ts,)* );
             assert!(tuple.is_default());
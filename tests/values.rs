@@ -0,0 +1,128 @@
+#[cfg(feature = "serde_json")]
+mod feature_serde_json {
+    use is_default::{IsDefault, PruneDefaults};
+    use serde_json::{Map, Value, json};
+
+    #[test]
+    fn value() {
+        assert!(Value::default().is_default());
+        assert!(Value::Null.is_default());
+        assert!(!json!(0).is_default());
+        assert!(!json!({}).is_default());
+        assert!(Map::new().is_default());
+        assert!(!json!({"a": null}).as_object().unwrap().is_default());
+    }
+
+    #[test]
+    fn prune_defaults() {
+        let mut v = json!({
+            "a": null,
+            "b": [1, null, 2, null, null],
+            "c": {"d": null, "e": {"f": null}},
+            "g": [null],
+            "h": 0,
+        });
+        v.prune_defaults();
+        assert_eq!(
+            v,
+            json!({
+                "b": [1, null, 2],
+                "c": {"e": {}},
+                "g": [],
+                "h": 0,
+            })
+        );
+    }
+}
+
+#[cfg(feature = "serde_yaml")]
+mod feature_serde_yaml {
+    use is_default::{IsDefault, PruneDefaults};
+    use serde_yaml::{Mapping, Value};
+
+    #[test]
+    fn value() {
+        assert!(Value::default().is_default());
+        assert!(!Value::from(0).is_default());
+        assert!(Mapping::new().is_default());
+    }
+
+    #[test]
+    fn prune_defaults() {
+        let mut v: Value = serde_yaml::from_str("a: ~\nb: [1, ~]\nc: {d: ~, e: 1}\n").unwrap();
+        v.prune_defaults();
+        let expected: Value = serde_yaml::from_str("b: [1]\nc: {e: 1}\n").unwrap();
+        assert_eq!(v, expected);
+    }
+}
+
+#[cfg(all(feature = "toml", not(feature = "via_default_eq")))]
+mod feature_toml {
+    use is_default::{IsDefault, PruneDefaults};
+    use toml::{Table, Value};
+
+    #[test]
+    fn value() {
+        assert!(Value::Array(Vec::new()).is_default());
+        assert!(Value::Table(Table::new()).is_default());
+        assert!(!Value::from(0).is_default());
+        assert!(!Value::from("").is_default());
+        assert!(!Value::from(false).is_default());
+        assert!(!Value::from(0.0).is_default());
+        assert!(!Value::Array(vec![Value::from(0)]).is_default());
+        assert!(Table::new().is_default());
+    }
+
+    #[test]
+    fn prune_defaults() {
+        let mut v: Table = toml::from_str(
+            r#"
+            name = ""
+            port = 0
+            hosts = ["a", [], {}]
+            empty = []
+
+            [tls]
+            enabled = false
+
+            [log]
+            level = "info"
+
+            [cache]
+            [cache.disk]
+            "#,
+        )
+        .unwrap();
+        v.prune_defaults();
+
+        let expected: Table = toml::from_str(
+            r#"
+            name = ""
+            port = 0
+            hosts = ["a"]
+
+            [tls]
+            enabled = false
+
+            [log]
+            level = "info"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(v, expected);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn prune_defaults_keeps_explicit_scalars_like_json() {
+        let source = "enabled = false\nport = 0\n";
+
+        let mut v: Table = toml::from_str(source).unwrap();
+        v.prune_defaults();
+        assert_eq!(v.len(), 2);
+
+        let mut json = serde_json::to_value(toml::from_str::<Table>(source).unwrap()).unwrap();
+        json.prune_defaults();
+        assert_eq!(serde_json::to_value(&v).unwrap(), json);
+    }
+}
@@ -0,0 +1,88 @@
+use is_default::Version;
+
+#[test]
+fn parse() {
+    assert_eq!("1".parse(), Ok(Version::new(1, 0, 0)));
+    assert_eq!("1.4".parse(), Ok(Version::new(1, 4, 0)));
+    assert_eq!("1.4.2".parse(), Ok(Version::new(1, 4, 2)));
+
+    for s in [
+        "",
+        "1.",
+        ".1",
+        "1.4.2.0",
+        "1.x",
+        "+1",
+        "1.-4",
+        "18446744073709551616",
+    ] {
+        assert!(s.parse::<Version>().is_err(), "{s}");
+        assert!(Version::parse(s).is_err(), "{s}");
+    }
+
+    const V: Version = match Version::parse("1.4") {
+        Ok(v) => v,
+        Err(_) => panic!(),
+    };
+    assert_eq!(V, Version::new(1, 4, 0));
+}
+
+#[test]
+fn ord() {
+    assert!(Version::new(1, 4, 0) < Version::new(1, 10, 0));
+    assert!(Version::new(1, 9, 9) < Version::new(2, 0, 0));
+    assert_eq!(Version::new(1, 4, 2).to_string(), "1.4.2");
+}
+
+#[cfg(all(feature = "derive", not(feature = "via_default_eq")))]
+mod derive {
+    use is_default::{IsDefault, Version};
+
+    #[derive(Default, IsDefault)]
+    struct Config {
+        jobs: u32,
+        #[is_default(since = "1.4")]
+        lto: bool,
+        #[is_default(since = "1.10.1")]
+        incremental: bool,
+    }
+
+    #[derive(Default, IsDefault)]
+    struct Tuple(u8, #[is_default(since = "2")] u8);
+
+    #[test]
+    fn requires_version() {
+        let mut c = Config {
+            jobs: 4,
+            ..Default::default()
+        };
+        assert_eq!(c.requires_version(), Version::new(0, 0, 0));
+
+        c.lto = true;
+        assert_eq!(c.requires_version(), Version::new(1, 4, 0));
+
+        c.incremental = true;
+        assert_eq!(c.requires_version(), Version::new(1, 10, 1));
+
+        assert_eq!(Tuple(0, 1).requires_version(), Version::new(2, 0, 0));
+    }
+
+    #[test]
+    fn is_default_since() {
+        let c = Config {
+            lto: true,
+            ..Default::default()
+        };
+        assert!(!c.is_default_since(Version::new(1, 3, 0)));
+        assert!(c.is_default_since(Version::new(1, 4, 0)));
+        assert!(c.is_default_since(Version::new(1, 9, 0)));
+        assert!(!c.is_default());
+
+        let c = Config {
+            incremental: true,
+            ..c
+        };
+        assert!(!c.is_default_since(Version::new(1, 9, 0)));
+        assert!(c.is_default_since(Version::new(1, 10, 1)));
+    }
+}
@@ -0,0 +1,74 @@
+use is_default::{
+    ConstDefault, WithDefault, WithDefaultBool, WithDefaultChar, WithDefaultI64, WithDefaultU8,
+    WithDefaultU128,
+};
+
+#[test]
+fn default_value() {
+    assert_eq!(WithDefault::<u16, 8080>::default().into_inner(), 8080);
+    assert_eq!(WithDefault::<i8, -128>::default().into_inner(), -128);
+    assert_eq!(
+        WithDefault::<u128, { i128::MAX }>::default_value(),
+        i128::MAX as u128
+    );
+    assert!(WithDefaultBool::<true>::default().into_inner());
+    assert_eq!(*WithDefaultChar::<'?'>::default(), '?');
+    assert_eq!(*WithDefaultI64::<-1>::default(), -1);
+    assert_eq!(*WithDefaultU128::<{ u128::MAX }>::default(), u128::MAX);
+    assert_eq!(WithDefaultU8::<7>::DEFAULT, WithDefaultU8::default());
+}
+
+#[test]
+fn conversions() {
+    let mut port = WithDefault::<u16, 8080>::from(443);
+    assert_eq!(*port, 443);
+    *port += 1;
+    assert_eq!(port, WithDefault::new(444));
+    assert_eq!(u8::from(WithDefaultU8::<7>::new(3)), 3);
+    assert_eq!(format!("{port:?} {port}"), "444 444");
+}
+
+#[cfg(not(feature = "via_default_eq"))]
+#[test]
+fn is_default() {
+    use is_default::{IsDefault, ResetDefault};
+
+    assert!(WithDefault::<u16, 8080>::new(8080).is_default());
+    assert!(!WithDefault::<u16, 8080>::new(0).is_default());
+    assert!(!WithDefaultBool::<true>::new(false).is_default());
+    assert!(WithDefaultI64::<-1>::new(-1).is_default());
+
+    let mut v = WithDefaultI64::<-1>::new(5);
+    v.reset_default();
+    assert_eq!(*v, -1);
+}
+
+#[cfg(all(feature = "derive", not(feature = "via_default_eq")))]
+#[test]
+fn derive() {
+    use is_default::IsDefault;
+
+    #[derive(Default, IsDefault)]
+    struct Server {
+        port: WithDefault<u16, 8080>,
+        tls: WithDefaultBool<true>,
+        retries: u8,
+    }
+
+    let mut server = Server::default();
+    assert_eq!(*server.port, 8080);
+    assert!(server.is_default());
+    *server.tls = false;
+    assert!(!server.is_default());
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn serde() {
+    let port: WithDefault<u16, 8080> = serde_json::from_str("443").unwrap();
+    assert_eq!(*port, 443);
+    assert_eq!(serde_json::to_string(&port).unwrap(), "443");
+
+    let tls: WithDefaultBool<true> = serde_json::from_str("false").unwrap();
+    assert_eq!(serde_json::to_string(&tls).unwrap(), "false");
+}
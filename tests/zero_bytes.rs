@@ -0,0 +1,130 @@
+use is_default::{IsZeroBytes, zero_bytes::is_zero_bytes};
+
+#[test]
+fn scan() {
+    assert!(is_zero_bytes(&[]));
+    for len in [1, 7, 8, 31, 32, 33, 100] {
+        let mut bytes = vec![0u8; len];
+        assert!(is_zero_bytes(&bytes));
+        for i in 0..len {
+            bytes[i] = 1;
+            assert!(!is_zero_bytes(&bytes), "len {len}, index {i}");
+            bytes[i] = 0;
+        }
+    }
+}
+
+#[test]
+fn primitives() {
+    assert!(0u64.is_default_bytes());
+    assert!(!1u64.is_default_bytes());
+    assert!(false.is_default_bytes());
+    assert!(!true.is_default_bytes());
+    assert!('\x00'.is_default_bytes());
+    assert!([0i32; 100].is_default_bytes());
+    assert!(![[0i32; 10], [0, 0, 0, 0, 0, 0, 0, 0, 0, -1]].is_default_bytes());
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use is_default::IsZeroBytes;
+
+    #[repr(C)]
+    #[derive(Default, IsZeroBytes)]
+    struct Header {
+        magic: u32,
+        flags: [u8; 4],
+        len: u64,
+    }
+
+    #[repr(transparent)]
+    #[derive(IsZeroBytes)]
+    struct Id(u128);
+
+    #[repr(C)]
+    #[derive(IsZeroBytes)]
+    union Word {
+        int: u64,
+        bytes: [u8; 8],
+    }
+
+    #[test]
+    fn structs() {
+        assert!(Header::default().is_default_bytes());
+        assert!(
+            !Header {
+                len: 1,
+                ..Default::default()
+            }
+            .is_default_bytes()
+        );
+        assert!(Id(0).is_default_bytes());
+        assert!(!Id(u128::MAX).is_default_bytes());
+    }
+
+    #[test]
+    fn unions() {
+        assert!(Word { int: 0 }.is_default_bytes());
+        assert!(
+            !Word {
+                bytes: [0, 0, 1, 0, 0, 0, 0, 0]
+            }
+            .is_default_bytes()
+        );
+    }
+
+    #[cfg(not(feature = "via_default_eq"))]
+    mod zeroed {
+        use is_default::{IsDefault, IsZeroBytes};
+
+        #[repr(C)]
+        #[derive(IsDefault, IsZeroBytes)]
+        #[is_default(zeroed)]
+        union Value {
+            int: i64,
+            float_bits: u64,
+        }
+
+        #[repr(C)]
+        #[derive(IsDefault, IsZeroBytes)]
+        #[is_default(zeroed)]
+        struct Matrix {
+            cells: [[u16; 32]; 32],
+        }
+
+        #[test]
+        fn is_default() {
+            assert!(Value { int: 0 }.is_default());
+            assert!(!Value { float_bits: 1 }.is_default());
+
+            let mut m = Matrix {
+                cells: [[0; 32]; 32],
+            };
+            assert!(m.is_default());
+            m.cells[31][31] = 1;
+            assert!(!m.is_default());
+        }
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn bytemuck() {
+    use is_default::zero_bytes::is_zeroed;
+
+    #[repr(C)]
+    #[derive(Clone, Copy, bytemuck::NoUninit, bytemuck::Zeroable)]
+    struct Vertex {
+        pos: [f32; 3],
+        color: u32,
+    }
+
+    assert!(is_zeroed(&Vertex {
+        pos: [0.0; 3],
+        color: 0
+    }));
+    assert!(!is_zeroed(&Vertex {
+        pos: [0.0, 1.0, 0.0],
+        color: 0
+    }));
+}